@@ -1,7 +1,13 @@
+use crate::cli::commands::PatchOptions;
+use crate::cli::completions;
+use arlon_core::infra::config::{ConfigFile, RenamesConfig, SigningConfig};
+use arlon_core::WhitespaceOptions;
 use clap::{Parser, Subcommand, ValueEnum};
+use clap_complete::ArgValueCandidates;
+use std::path::PathBuf;
 
 #[derive(Parser)]
-#[command(name = "arlon")]
+#[command(name = "arlon", version)]
 #[command(about = "Compare branches and files")]
 pub struct Args {
     #[command(subcommand)]
@@ -10,36 +16,802 @@ pub struct Args {
 
 #[derive(Subcommand)]
 pub enum Commands {
-    Commits {
-        #[arg(help = "Branch name to compare against")]
-        branch: String,
-
-        #[arg(
-            short,
-            long,
-            value_enum,
-            default_value = "simple",
-            help = "Output format"
-        )]
-        format: OutputFormat,
+    #[command(about = "Show commits in HEAD that are not in the specified branch")]
+    Commits(CommitsArgs),
+    #[command(about = "Show files that differ between branches")]
+    Files(FilesArgs),
+    #[command(
+        about = "Summarize the authors of commits in HEAD that are not in the specified branch"
+    )]
+    Authors(AuthorsArgs),
+    #[command(
+        about = "List issues referenced by commits in HEAD that are not in the specified branch"
+    )]
+    Issues(IssuesArgs),
+    #[command(
+        about = "Check the messages of commits in HEAD that are not in the specified branch"
+    )]
+    Lint(LintArgs),
+    #[command(about = "List workspace packages with files that differ between branches")]
+    Affected(AffectedArgs),
+    #[command(about = "Generate a changelog from the Conventional Commits between two refs")]
+    Changelog(ChangelogArgs),
+    #[command(about = "Print a shell completion script")]
+    Completions {
+        #[arg(value_enum, help = "Shell to generate the completion script for")]
+        shell: CompletionShell,
     },
-    Files {
-        #[arg(help = "Branch name to compare against")]
-        branch: String,
-
-        #[arg(
-            short,
-            long,
-            value_enum,
-            default_value = "simple",
-            help = "Output format"
-        )]
-        format: OutputFormat,
+    #[command(about = "Generate man pages", hide = true)]
+    Man {
+        #[arg(help = "Directory to write the man pages to")]
+        out_dir: PathBuf,
     },
 }
 
-#[derive(Clone, ValueEnum)]
+/// The branch to compare against and the output format, shared by every
+/// command that compares HEAD with a base branch.
+#[derive(clap::Args)]
+pub struct BaseArgs {
+    #[arg(
+        help = "Branch name to compare against [default: `base` from config, else detected]",
+        add = ArgValueCandidates::new(completions::branch_candidates)
+    )]
+    pub branch: Option<String>,
+
+    #[arg(short, long, value_enum, help = "Output format [default: simple]")]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(clap::Args)]
+pub struct CompareArgs {
+    #[command(flatten)]
+    pub base: BaseArgs,
+
+    #[arg(
+        long,
+        help = "Exit with 1 if there are differences and 0 if there are none"
+    )]
+    pub exit_code: bool,
+}
+
+/// Lines of context around changes, as in `git diff`.
+const DEFAULT_CONTEXT_LINES: u32 = 3;
+
+#[derive(clap::Args)]
+pub struct PatchArgs {
+    #[arg(
+        short = 'p',
+        long,
+        help = "Show the changes to each file as a unified diff"
+    )]
+    pub patch: bool,
+
+    #[arg(
+        short = 'U',
+        long,
+        value_name = "LINES",
+        help = "Lines of context around changes in patches [default: 3]; implies --patch"
+    )]
+    pub unified: Option<u32>,
+
+    #[arg(
+        long,
+        help = "Show changed words inline in patches, as git diff --word-diff; implies --patch"
+    )]
+    pub word_diff: bool,
+}
+
+#[derive(clap::Args)]
+pub struct WhitespaceArgs {
+    #[arg(short = 'w', long, help = "Ignore all whitespace when comparing lines")]
+    pub ignore_whitespace: bool,
+
+    #[arg(short = 'b', long, help = "Ignore changes in the amount of whitespace")]
+    pub ignore_space_change: bool,
+
+    #[arg(long, help = "Ignore changes whose lines are all blank")]
+    pub ignore_blank_lines: bool,
+
+    #[arg(long, help = "Ignore carriage returns at the end of lines")]
+    pub ignore_cr_at_eol: bool,
+}
+
+#[derive(clap::Args)]
+pub struct CommitsArgs {
+    #[command(flatten)]
+    pub compare: CompareArgs,
+
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        help = "strftime-style format for commit dates [default: %Y-%m-%d %H:%M:%S]"
+    )]
+    pub date_format: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "LENGTH",
+        help = "Shorten commit hashes in simple output to LENGTH characters"
+    )]
+    pub abbrev: Option<usize>,
+
+    #[arg(
+        long,
+        help = "List the files each commit changed relative to its first parent"
+    )]
+    pub name_status: bool,
+
+    #[command(flatten)]
+    pub patch: PatchArgs,
+
+    #[command(flatten)]
+    pub whitespace: WhitespaceArgs,
+
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        help = "Only show commits without a TOKEN trailer, such as Signed-off-by, and exit with 1 if there are any"
+    )]
+    pub require_trailer: Vec<String>,
+
+    #[arg(
+        long,
+        help = "Verify commit signatures against the GPG keyring or SSH allowed signers; X.509 signatures are reported as unknown_key"
+    )]
+    pub verify_signatures: bool,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "GPG keyring to verify signatures against instead of the default one"
+    )]
+    pub gpg_keyring: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "SSH allowed signers file to verify signatures against [default: gpg.ssh.allowedSignersFile]"
+    )]
+    pub allowed_signers: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Only show unsigned commits, or with --verify-signatures commits without a good signature, and exit with 1 if there are any"
+    )]
+    pub require_signed: bool,
+}
+
+#[derive(clap::Args)]
+pub struct AuthorsArgs {
+    #[command(flatten)]
+    pub compare: CompareArgs,
+
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        help = "strftime-style format for first and last commit dates [default: %Y-%m-%d %H:%M:%S]"
+    )]
+    pub date_format: Option<String>,
+
+    #[arg(long, help = "Also count lines added and removed per author")]
+    pub lines: bool,
+}
+
+#[derive(clap::Args)]
+pub struct IssuesArgs {
+    #[command(flatten)]
+    pub compare: CompareArgs,
+
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        help = "strftime-style format for commit dates [default: %Y-%m-%d %H:%M:%S]"
+    )]
+    pub date_format: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "LENGTH",
+        help = "Shorten commit hashes in simple output to LENGTH characters"
+    )]
+    pub abbrev: Option<usize>,
+}
+
+#[derive(clap::Args)]
+pub struct LintArgs {
+    #[command(flatten)]
+    pub base: BaseArgs,
+
+    #[arg(
+        long,
+        value_name = "LENGTH",
+        help = "Shorten commit hashes in simple output to LENGTH characters"
+    )]
+    pub abbrev: Option<usize>,
+}
+
+#[derive(clap::Args)]
+pub struct AffectedArgs {
+    #[command(flatten)]
+    pub compare: CompareArgs,
+}
+
+#[derive(clap::Args)]
+pub struct ChangelogArgs {
+    #[arg(
+        help = "Branch or tag the changelog starts from, usually the last release",
+        add = ArgValueCandidates::new(completions::branch_candidates)
+    )]
+    pub from: String,
+
+    #[arg(
+        help = "Branch or tag the changelog ends at [default: HEAD]",
+        add = ArgValueCandidates::new(completions::branch_candidates)
+    )]
+    pub to: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        value_enum,
+        help = "Output format, simple being Markdown [default: simple]"
+    )]
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(clap::Args)]
+pub struct FilesArgs {
+    #[command(flatten)]
+    pub compare: CompareArgs,
+
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "50",
+        help = "Detect renames with the given similarity threshold"
+    )]
+    pub find_renames: Option<u16>,
+
+    #[arg(
+        long,
+        conflicts_with = "find_renames",
+        help = "Disable rename detection"
+    )]
+    pub no_renames: bool,
+
+    #[arg(
+        long,
+        value_name = "DEPTH",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "2",
+        value_parser = clap::value_parser!(u16).range(1..),
+        conflicts_with_all = ["patch", "unified", "word_diff"],
+        help = "Summarize changed lines per directory, grouped to DEPTH levels [default: 2]"
+    )]
+    pub dirstat: Option<u16>,
+
+    #[arg(
+        long,
+        conflicts_with = "dirstat",
+        help = "Show the code owners of each changed file from CODEOWNERS in HEAD"
+    )]
+    pub owners: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["dirstat", "owners", "patch", "unified", "word_diff"],
+        help = "Group changed files by code owner"
+    )]
+    pub by_owner: bool,
+
+    #[arg(
+        long,
+        value_name = "GLOB",
+        help = "Hide files matching GLOB, replacing the configured exclude patterns; repeatable"
+    )]
+    pub exclude: Vec<String>,
+
+    #[arg(
+        long,
+        help = "List files marked linguist-generated or -diff in .gitattributes instead of hiding them"
+    )]
+    pub show_generated: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["dirstat", "by_owner"],
+        help = "List the commits between the old and new pointers of changed submodules that are checked out"
+    )]
+    pub recurse_submodules: bool,
+
+    #[command(flatten)]
+    pub patch: PatchArgs,
+
+    #[command(flatten)]
+    pub whitespace: WhitespaceArgs,
+}
+
+impl Commands {
+    pub fn format(&self) -> Option<&OutputFormat> {
+        match self {
+            Commands::Commits(args) => args.compare.base.format.as_ref(),
+            Commands::Files(args) => args.compare.base.format.as_ref(),
+            Commands::Authors(args) => args.compare.base.format.as_ref(),
+            Commands::Issues(args) => args.compare.base.format.as_ref(),
+            Commands::Lint(args) => args.base.format.as_ref(),
+            Commands::Affected(args) => args.compare.base.format.as_ref(),
+            Commands::Changelog(args) => args.format.as_ref(),
+            Commands::Completions { .. } | Commands::Man { .. } => None,
+        }
+    }
+}
+
+impl BaseArgs {
+    fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            base: self.branch.clone(),
+            format: self.format.as_ref().map(|format| format.name().to_string()),
+            ..ConfigFile::default()
+        }
+    }
+}
+
+impl CompareArgs {
+    fn overrides(&self) -> ConfigFile {
+        self.base.overrides()
+    }
+}
+
+impl PatchArgs {
+    pub fn options(&self) -> PatchOptions {
+        let patch = self.patch || self.unified.is_some() || self.word_diff;
+        PatchOptions {
+            context_lines: patch.then(|| self.unified.unwrap_or(DEFAULT_CONTEXT_LINES)),
+            word_diff: self.word_diff,
+        }
+    }
+}
+
+impl WhitespaceArgs {
+    pub fn options(&self) -> WhitespaceOptions {
+        WhitespaceOptions {
+            ignore_all_space: self.ignore_whitespace,
+            ignore_space_change: self.ignore_space_change,
+            ignore_blank_lines: self.ignore_blank_lines,
+            ignore_cr_at_eol: self.ignore_cr_at_eol,
+        }
+    }
+}
+
+impl CommitsArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            date_format: self.date_format.clone(),
+            abbrev: self.abbrev,
+            signing: (self.gpg_keyring.is_some() || self.allowed_signers.is_some()).then(|| {
+                SigningConfig {
+                    gpg_keyring: self.gpg_keyring.clone(),
+                    allowed_signers: self.allowed_signers.clone(),
+                }
+            }),
+            ..self.compare.overrides()
+        }
+    }
+}
+
+impl AuthorsArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            date_format: self.date_format.clone(),
+            ..self.compare.overrides()
+        }
+    }
+}
+
+impl IssuesArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            date_format: self.date_format.clone(),
+            abbrev: self.abbrev,
+            ..self.compare.overrides()
+        }
+    }
+}
+
+impl LintArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            abbrev: self.abbrev,
+            ..self.base.overrides()
+        }
+    }
+}
+
+impl AffectedArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        self.compare.overrides()
+    }
+}
+
+impl ChangelogArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        ConfigFile {
+            base: Some(self.from.clone()),
+            format: self.format.as_ref().map(|format| format.name().to_string()),
+            ..ConfigFile::default()
+        }
+    }
+}
+
+impl FilesArgs {
+    /// Values given on the command line, layered over config files.
+    pub fn overrides(&self) -> ConfigFile {
+        let renames = match (self.find_renames, self.no_renames) {
+            (Some(threshold), _) => Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(threshold),
+                limit: None,
+            }),
+            (None, true) => Some(RenamesConfig {
+                enabled: Some(false),
+                threshold: None,
+                limit: None,
+            }),
+            (None, false) => None,
+        };
+
+        ConfigFile {
+            renames,
+            exclude: (!self.exclude.is_empty()).then(|| self.exclude.clone()),
+            ..self.compare.overrides()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ValueEnum)]
 pub enum OutputFormat {
     Simple,
     Json,
 }
+
+impl OutputFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Simple => "simple",
+            OutputFormat::Json => "json",
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_args_definition() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_completions() {
+        let args = Args::try_parse_from(["arlon", "completions", "zsh"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Commands::Completions {
+                shell: CompletionShell::Zsh
+            }
+        ));
+        assert!(args.command.format().is_none());
+    }
+
+    #[test]
+    fn test_commits_overrides() {
+        let args = Args::try_parse_from([
+            "arlon",
+            "commits",
+            "main",
+            "-f",
+            "json",
+            "--date-format",
+            "%F",
+            "--abbrev",
+            "10",
+        ])
+        .unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+
+        let overrides = commits.overrides();
+        assert_eq!(overrides.base.as_deref(), Some("main"));
+        assert_eq!(overrides.format.as_deref(), Some("json"));
+        assert_eq!(overrides.date_format.as_deref(), Some("%F"));
+        assert_eq!(overrides.abbrev, Some(10));
+        assert!(commits.require_trailer.is_empty());
+        assert!(!commits.verify_signatures);
+        assert!(!commits.require_signed);
+        assert!(!commits.name_status);
+        assert_eq!(commits.patch.options(), PatchOptions::default());
+    }
+
+    #[test]
+    fn test_whitespace_options() {
+        let args =
+            Args::try_parse_from(["arlon", "files", "main", "-w", "--ignore-cr-at-eol"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(
+            files.whitespace.options(),
+            WhitespaceOptions {
+                ignore_all_space: true,
+                ignore_cr_at_eol: true,
+                ..WhitespaceOptions::default()
+            }
+        );
+
+        let args = Args::try_parse_from(["arlon", "commits", "main", "-p", "-b"]).unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+        assert!(commits.whitespace.options().ignore_space_change);
+    }
+
+    #[test]
+    fn test_patch_options() {
+        let args = Args::try_parse_from(["arlon", "files", "main", "-U1"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(
+            files.patch.options(),
+            PatchOptions {
+                context_lines: Some(1),
+                word_diff: false,
+            }
+        );
+
+        let args = Args::try_parse_from(["arlon", "commits", "main", "--word-diff"]).unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+        assert_eq!(
+            commits.patch.options(),
+            PatchOptions {
+                context_lines: Some(3),
+                word_diff: true,
+            }
+        );
+
+        assert!(Args::try_parse_from(["arlon", "files", "main", "--patch", "--dirstat"]).is_err());
+    }
+
+    #[test]
+    fn test_commits_require_trailer() {
+        let args = Args::try_parse_from([
+            "arlon",
+            "commits",
+            "main",
+            "--require-trailer",
+            "Signed-off-by",
+            "--require-trailer",
+            "Reviewed-by",
+        ])
+        .unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+
+        assert_eq!(
+            commits.require_trailer,
+            vec!["Signed-off-by", "Reviewed-by"]
+        );
+    }
+
+    #[test]
+    fn test_commits_require_signed() {
+        let args = Args::try_parse_from([
+            "arlon",
+            "commits",
+            "main",
+            "--verify-signatures",
+            "--require-signed",
+        ])
+        .unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+
+        assert!(commits.verify_signatures);
+        assert!(commits.require_signed);
+        assert_eq!(commits.overrides().signing, None);
+    }
+
+    #[test]
+    fn test_commits_signing_overrides() {
+        let args = Args::try_parse_from([
+            "arlon",
+            "commits",
+            "main",
+            "--verify-signatures",
+            "--allowed-signers",
+            "trusted_signers",
+        ])
+        .unwrap();
+        let Commands::Commits(commits) = args.command else {
+            panic!("Expected commits command");
+        };
+
+        assert_eq!(
+            commits.overrides().signing,
+            Some(SigningConfig {
+                gpg_keyring: None,
+                allowed_signers: Some(PathBuf::from("trusted_signers")),
+            })
+        );
+    }
+
+    #[test]
+    fn test_authors_args() {
+        let args = Args::try_parse_from(["arlon", "authors", "main", "--lines"]).unwrap();
+        let Commands::Authors(authors) = args.command else {
+            panic!("Expected authors command");
+        };
+
+        assert!(authors.lines);
+        assert_eq!(authors.overrides().base.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn test_issues_args() {
+        let args =
+            Args::try_parse_from(["arlon", "issues", "main", "--abbrev", "8", "--exit-code"])
+                .unwrap();
+
+        let Commands::Issues(issues) = args.command else {
+            panic!("Expected issues command");
+        };
+        assert!(issues.compare.exit_code);
+        assert_eq!(issues.overrides().base.as_deref(), Some("main"));
+        assert_eq!(issues.overrides().abbrev, Some(8));
+    }
+
+    #[test]
+    fn test_lint_args() {
+        let args = Args::try_parse_from(["arlon", "lint", "main", "-f", "json"]).unwrap();
+        let Commands::Lint(lint) = args.command else {
+            panic!("Expected lint command");
+        };
+
+        let overrides = lint.overrides();
+        assert_eq!(overrides.base.as_deref(), Some("main"));
+        assert_eq!(overrides.format.as_deref(), Some("json"));
+        assert!(Args::try_parse_from(["arlon", "lint", "main", "--exit-code"]).is_err());
+    }
+
+    #[test]
+    fn test_affected_args() {
+        let args = Args::try_parse_from(["arlon", "affected", "main", "-f", "json"]).unwrap();
+        assert_eq!(args.command.format(), Some(&OutputFormat::Json));
+        let Commands::Affected(affected) = args.command else {
+            panic!("Expected affected command");
+        };
+
+        assert_eq!(affected.overrides().base.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn test_changelog_args() {
+        let args = Args::try_parse_from(["arlon", "changelog", "v1.0.0"]).unwrap();
+        let Commands::Changelog(changelog) = args.command else {
+            panic!("Expected changelog command");
+        };
+        assert_eq!(changelog.to, None);
+        assert_eq!(changelog.overrides().base.as_deref(), Some("v1.0.0"));
+
+        let args = Args::try_parse_from(["arlon", "changelog", "v1.0.0", "release"]).unwrap();
+        let Commands::Changelog(changelog) = args.command else {
+            panic!("Expected changelog command");
+        };
+        assert_eq!(changelog.to.as_deref(), Some("release"));
+
+        assert!(Args::try_parse_from(["arlon", "changelog"]).is_err());
+    }
+
+    #[test]
+    fn test_files_overrides_without_branch() {
+        let args = Args::try_parse_from(["arlon", "files", "--find-renames"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+
+        let overrides = files.overrides();
+        assert_eq!(overrides.base, None);
+        assert_eq!(overrides.format, None);
+        assert_eq!(
+            overrides.renames,
+            Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(50),
+                limit: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_files_dirstat() {
+        let args = Args::try_parse_from(["arlon", "files", "main", "--dirstat"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(files.dirstat, Some(2));
+
+        let args = Args::try_parse_from(["arlon", "files", "--dirstat=1"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(files.dirstat, Some(1));
+
+        assert!(Args::try_parse_from(["arlon", "files", "--dirstat=0"]).is_err());
+        assert!(
+            Args::try_parse_from(["arlon", "files", "--dirstat", "--recurse-submodules"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_files_owner_flags() {
+        let args = Args::try_parse_from(["arlon", "files", "main", "--by-owner"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert!(files.by_owner);
+        assert!(!files.owners);
+
+        assert!(Args::try_parse_from(["arlon", "files", "--owners", "--dirstat"]).is_err());
+        assert!(Args::try_parse_from(["arlon", "files", "--owners", "--by-owner"]).is_err());
+    }
+
+    #[test]
+    fn test_files_exclude_overrides() {
+        let args =
+            Args::try_parse_from(["arlon", "files", "--exclude", "*.lock", "--exclude=dist/**"])
+                .unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(
+            files.overrides().exclude,
+            Some(vec!["*.lock".to_string(), "dist/**".to_string()])
+        );
+
+        let args = Args::try_parse_from(["arlon", "files"]).unwrap();
+        let Commands::Files(files) = args.command else {
+            panic!("Expected files command");
+        };
+        assert_eq!(files.overrides().exclude, None);
+    }
+
+    #[test]
+    fn test_files_no_renames_conflicts_with_find_renames() {
+        let result = Args::try_parse_from(["arlon", "files", "--find-renames=60", "--no-renames"]);
+        assert!(result.is_err());
+    }
+}
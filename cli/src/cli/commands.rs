@@ -1,22 +1,77 @@
-use crate::cli::{Commands, OutputFormat};
-use arlon_core::application::use_cases::{compare_commits, compare_files};
+use crate::cli::settings::SettingsError;
+use crate::cli::{ExitCode, OutputFormat, Settings};
+use arlon_core::application::use_cases::{
+    compare_authors, compare_commits, compare_files, compare_issues, detect_affected_packages,
+    detect_base_branch, generate_changelog, lint_commits,
+};
 use arlon_core::{
-    CompareCommitsUseCase, CompareFilesUseCase, GitRepository, GitRepositoryImpl, JsonFormatter,
-    OutputFormatter, SimpleFormatter,
+    BranchName, BranchNameError, ChangelogOutputDto, CommitDto, CompareAuthorsUseCase,
+    CompareCommitsUseCase, CompareFilesUseCase, CompareIssuesUseCase, ComparisonEndpoints,
+    ConfigError, ConfigFile, ConfigLoader, DetectAffectedPackagesUseCase, DetectBaseBranchUseCase,
+    ErrorDto, FileComparisonOptions, GenerateChangelogUseCase, GitRepository, GitRepositoryImpl,
+    HiddenFilesDto, JsonFormatter, LintCommitsUseCase, OutputDto, OutputFormatter, SimpleFormatter,
 };
 
+const TARGET_REF: &str = "HEAD";
+
+/// How `arlon files` and `arlon commits` show patches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PatchOptions {
+    /// Lines of context around changes; `None` shows no patches.
+    pub context_lines: Option<u32>,
+    pub word_diff: bool,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CommandError {
     #[error("Compare commits failed: {0}")]
     CompareCommits(#[from] compare_commits::CompareCommitsError),
     #[error("Compare files failed: {0}")]
     CompareFiles(#[from] compare_files::CompareFilesError),
+    #[error("Compare authors failed: {0}")]
+    CompareAuthors(#[from] compare_authors::CompareAuthorsError),
+    #[error("Compare issues failed: {0}")]
+    CompareIssues(#[from] compare_issues::CompareIssuesError),
+    #[error("Detect affected packages failed: {0}")]
+    DetectAffectedPackages(#[from] detect_affected_packages::DetectAffectedPackagesError),
+    #[error("Lint commits failed: {0}")]
+    LintCommits(#[from] lint_commits::LintCommitsError),
+    #[error("Generate changelog failed: {0}")]
+    GenerateChangelog(#[from] generate_changelog::GenerateChangelogError),
+    #[error("{0}")]
+    DetectBaseBranch(#[from] detect_base_branch::DetectBaseBranchError),
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
     #[error("Repository error: {0}")]
     Repository(#[from] arlon_core::GitRepositoryError),
+    #[error("Config error: {0}")]
+    Config(#[from] ConfigError),
+    #[error("Invalid settings: {0}")]
+    Settings(#[from] SettingsError),
     #[error("Output error: {0}")]
     Output(String),
 }
 
+impl From<&CommandError> for ErrorDto {
+    fn from(error: &CommandError) -> Self {
+        match error {
+            CommandError::CompareCommits(e) => ErrorDto::from(e),
+            CommandError::CompareFiles(e) => ErrorDto::from(e),
+            CommandError::CompareAuthors(e) => ErrorDto::from(e),
+            CommandError::CompareIssues(e) => ErrorDto::from(e),
+            CommandError::DetectAffectedPackages(e) => ErrorDto::from(e),
+            CommandError::LintCommits(e) => ErrorDto::from(e),
+            CommandError::GenerateChangelog(e) => ErrorDto::from(e),
+            CommandError::DetectBaseBranch(e) => ErrorDto::new(e.kind(), None, e.to_string()),
+            CommandError::InvalidBranchName(e) => ErrorDto::from(e),
+            CommandError::Repository(e) => ErrorDto::from(e),
+            CommandError::Config(e) => ErrorDto::new("invalid_config", None, e.to_string()),
+            CommandError::Settings(e) => ErrorDto::new(e.kind(), None, e.to_string()),
+            CommandError::Output(message) => ErrorDto::new("output_error", None, message.clone()),
+        }
+    }
+}
+
 pub struct CommandController<R> {
     git_repository: R,
 }
@@ -26,63 +81,423 @@ impl<R: GitRepository> CommandController<R> {
         Self { git_repository }
     }
 
-    pub fn execute(&self, command: Commands) -> Result<(), CommandError> {
-        match command {
-            Commands::Commits { branch, format } => self.handle_compare_commits(branch, format),
-            Commands::Files { branch, format } => self.handle_compare_files(branch, format),
+    /// Lists the commits not in the base branch, or with `required_trailers`
+    /// only those lacking one of them, which always counts as a difference.
+    pub fn compare_commits(
+        &self,
+        settings: &Settings,
+        required_trailers: Vec<String>,
+        verify_signatures: bool,
+        require_signed: bool,
+        name_status: bool,
+        patch: PatchOptions,
+    ) -> Result<ExitCode, CommandError> {
+        let file_options = FileComparisonOptions {
+            patch_context: patch.context_lines,
+            ..settings.file_options.clone()
+        };
+        let checking = !required_trailers.is_empty() || require_signed;
+        let use_case = CompareCommitsUseCase::new(&self.git_repository)
+            .with_date_format(settings.date_format.clone())
+            .with_issue_patterns(settings.issue_patterns.clone())
+            .with_required_trailers(required_trailers)
+            .with_signature_verification(
+                verify_signatures.then(|| settings.signature_verification.clone()),
+            )
+            .with_require_signed(require_signed)
+            .with_name_status(
+                (name_status || patch.context_lines.is_some()).then_some(file_options),
+            );
+        let base = self.resolve_base(settings)?;
+        let commits = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output =
+            OutputDto::new(base, TARGET_REF.to_string(), commits).with_endpoints(&endpoints);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default()
+                    .with_abbrev(settings.abbrev)
+                    .with_encoding(settings.encoding)
+                    .with_signatures(verify_signatures || require_signed)
+                    .with_word_diff(patch.word_diff);
+                formatter
+                    .format_commits(&output)
+                    .map_err(CommandError::Output)?;
+                report_warnings(&output.items);
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_commits(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code || checking,
+            !output.items.is_empty(),
+        ))
+    }
+
+    pub fn compare_files(
+        &self,
+        settings: &Settings,
+        owners: bool,
+        show_generated: bool,
+        recurse_submodules: bool,
+        patch: PatchOptions,
+    ) -> Result<ExitCode, CommandError> {
+        let use_case = CompareFilesUseCase::new(&self.git_repository)
+            .with_options(FileComparisonOptions {
+                patch_context: patch.context_lines,
+                recurse_submodules,
+                ..settings.file_options.clone()
+            })
+            .with_excludes(settings.excludes.clone())
+            .with_owners(owners)
+            .with_show_generated(show_generated);
+        let base = self.resolve_base(settings)?;
+        let (files, hidden) = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output = OutputDto::new(base, TARGET_REF.to_string(), files)
+            .with_endpoints(&endpoints)
+            .with_hidden(hidden);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default()
+                    .with_encoding(settings.encoding)
+                    .with_word_diff(patch.word_diff);
+                formatter
+                    .format_files(&output)
+                    .map_err(CommandError::Output)?;
+                report_hidden(&hidden);
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_files(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
+    }
+
+    pub fn compare_files_by_owner(
+        &self,
+        settings: &Settings,
+        show_generated: bool,
+    ) -> Result<ExitCode, CommandError> {
+        let use_case = CompareFilesUseCase::new(&self.git_repository)
+            .with_options(settings.file_options.clone())
+            .with_excludes(settings.excludes.clone())
+            .with_show_generated(show_generated);
+        let base = self.resolve_base(settings)?;
+        let (groups, hidden) = use_case.execute_by_owner(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output = OutputDto::new(base, TARGET_REF.to_string(), groups)
+            .with_endpoints(&endpoints)
+            .with_hidden(hidden);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default().with_encoding(settings.encoding);
+                formatter
+                    .format_owners(&output)
+                    .map_err(CommandError::Output)?;
+                report_hidden(&hidden);
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_owners(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
+    }
+
+    pub fn compare_dirstat(
+        &self,
+        settings: &Settings,
+        depth: usize,
+        show_generated: bool,
+    ) -> Result<ExitCode, CommandError> {
+        let use_case = CompareFilesUseCase::new(&self.git_repository)
+            .with_options(settings.file_options.clone())
+            .with_excludes(settings.excludes.clone())
+            .with_show_generated(show_generated);
+        let base = self.resolve_base(settings)?;
+        let (directories, hidden) = use_case.execute_dirstat(base.clone(), depth)?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output = OutputDto::new(base, TARGET_REF.to_string(), directories)
+            .with_endpoints(&endpoints)
+            .with_hidden(hidden);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default().with_encoding(settings.encoding);
+                formatter
+                    .format_dirstat(&output)
+                    .map_err(CommandError::Output)?;
+                report_hidden(&hidden);
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_dirstat(&output)
+                    .map_err(CommandError::Output)?;
+            }
         }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
     }
 
-    fn handle_compare_commits(
+    pub fn compare_authors(
         &self,
-        branch: String,
-        format: OutputFormat,
-    ) -> Result<(), CommandError> {
-        let use_case = CompareCommitsUseCase::new(&self.git_repository);
-        let commits = use_case.execute(branch)?;
+        settings: &Settings,
+        line_stats: bool,
+    ) -> Result<ExitCode, CommandError> {
+        let use_case = CompareAuthorsUseCase::new(&self.git_repository)
+            .with_date_format(settings.date_format.clone())
+            .with_line_stats(line_stats);
+        let base = self.resolve_base(settings)?;
+        let authors = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output =
+            OutputDto::new(base, TARGET_REF.to_string(), authors).with_endpoints(&endpoints);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default().with_encoding(settings.encoding);
+                formatter
+                    .format_authors(&output)
+                    .map_err(CommandError::Output)?;
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_authors(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
+    }
+
+    pub fn compare_issues(&self, settings: &Settings) -> Result<ExitCode, CommandError> {
+        let use_case = CompareIssuesUseCase::new(&self.git_repository)
+            .with_date_format(settings.date_format.clone())
+            .with_issue_patterns(settings.issue_patterns.clone());
+        let base = self.resolve_base(settings)?;
+        let issues = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output =
+            OutputDto::new(base, TARGET_REF.to_string(), issues).with_endpoints(&endpoints);
 
-        match format {
+        match settings.format {
             OutputFormat::Simple => {
-                let formatter = SimpleFormatter;
+                let formatter = SimpleFormatter::default()
+                    .with_abbrev(settings.abbrev)
+                    .with_encoding(settings.encoding);
                 formatter
-                    .format_commits(&commits)
+                    .format_issues(&output)
                     .map_err(CommandError::Output)?;
             }
             OutputFormat::Json => {
                 let formatter = JsonFormatter;
                 formatter
-                    .format_commits(&commits)
+                    .format_issues(&output)
                     .map_err(CommandError::Output)?;
             }
         }
 
-        Ok(())
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
     }
 
-    fn handle_compare_files(
+    /// Reports the commits whose messages break lint rules. Any violation
+    /// counts as a difference so that CI fails.
+    pub fn lint_commits(&self, settings: &Settings) -> Result<ExitCode, CommandError> {
+        let use_case = LintCommitsUseCase::new(&self.git_repository)
+            .with_date_format(settings.date_format.clone())
+            .with_issue_patterns(settings.issue_patterns.clone())
+            .with_rules(settings.lint_rules.clone());
+        let base = self.resolve_base(settings)?;
+        let results = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output =
+            OutputDto::new(base, TARGET_REF.to_string(), results).with_endpoints(&endpoints);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default()
+                    .with_abbrev(settings.abbrev)
+                    .with_encoding(settings.encoding);
+                formatter
+                    .format_lint(&output)
+                    .map_err(CommandError::Output)?;
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_lint(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(true, !output.items.is_empty()))
+    }
+
+    pub fn detect_affected_packages(&self, settings: &Settings) -> Result<ExitCode, CommandError> {
+        let use_case = DetectAffectedPackagesUseCase::new(&self.git_repository)
+            .with_options(settings.file_options.clone())
+            .with_packages(settings.packages.clone());
+        let base = self.resolve_base(settings)?;
+        let packages = use_case.execute(base.clone())?;
+        let endpoints = self.endpoints(&base, None)?;
+        let output =
+            OutputDto::new(base, TARGET_REF.to_string(), packages).with_endpoints(&endpoints);
+
+        match settings.format {
+            OutputFormat::Simple => {
+                let formatter = SimpleFormatter::default().with_encoding(settings.encoding);
+                formatter
+                    .format_packages(&output)
+                    .map_err(CommandError::Output)?;
+            }
+            OutputFormat::Json => {
+                let formatter = JsonFormatter;
+                formatter
+                    .format_packages(&output)
+                    .map_err(CommandError::Output)?;
+            }
+        }
+
+        Ok(ExitCode::for_differences(
+            settings.exit_code,
+            !output.items.is_empty(),
+        ))
+    }
+
+    /// Writes the changelog from the base branch to `to`. In simple format
+    /// the suggested version bump goes to stderr so stdout is plain
+    /// Markdown.
+    pub fn generate_changelog(
         &self,
-        branch: String,
-        format: OutputFormat,
-    ) -> Result<(), CommandError> {
-        let use_case = CompareFilesUseCase::new(&self.git_repository);
-        let files = use_case.execute(branch)?;
+        settings: &Settings,
+        to: Option<String>,
+    ) -> Result<ExitCode, CommandError> {
+        let use_case = GenerateChangelogUseCase::new(&self.git_repository);
+        let base = self.resolve_base(settings)?;
+        let target = to.clone().unwrap_or_else(|| TARGET_REF.to_string());
+        let changelog = use_case.execute(base.clone(), to.clone())?;
+        let endpoints = self.endpoints(&base, to.as_deref())?;
+        let output = ChangelogOutputDto::new(base, target, changelog).with_endpoints(&endpoints);
 
-        match format {
+        match settings.format {
             OutputFormat::Simple => {
-                let formatter = SimpleFormatter;
+                let formatter = SimpleFormatter::default()
+                    .with_abbrev(settings.abbrev)
+                    .with_encoding(settings.encoding);
                 formatter
-                    .format_files(&files)
+                    .format_changelog(&output)
                     .map_err(CommandError::Output)?;
+                match &output.changelog.next_version {
+                    Some(version) => eprintln!(
+                        "Suggested version bump: {} ({})",
+                        output.changelog.bump, version
+                    ),
+                    None => eprintln!("Suggested version bump: {}", output.changelog.bump),
+                }
             }
             OutputFormat::Json => {
                 let formatter = JsonFormatter;
                 formatter
-                    .format_files(&files)
+                    .format_changelog(&output)
                     .map_err(CommandError::Output)?;
             }
         }
 
-        Ok(())
+        Ok(ExitCode::Success)
+    }
+
+    /// The commits `base` and `target`, or HEAD when `None`, resolve to,
+    /// recorded in the output envelope.
+    fn endpoints(
+        &self,
+        base: &str,
+        target: Option<&str>,
+    ) -> Result<ComparisonEndpoints, CommandError> {
+        let base = BranchName::new(base.to_string())?;
+        let target = target
+            .map(|target| BranchName::new(target.to_string()))
+            .transpose()?;
+        Ok(self.git_repository.resolve_endpoints(&base, target)?)
+    }
+
+    /// The configured base branch, or the detected one. The detected branch
+    /// is announced on stderr so stdout stays machine-readable.
+    fn resolve_base(&self, settings: &Settings) -> Result<String, CommandError> {
+        if let Some(base) = &settings.base {
+            return Ok(base.clone());
+        }
+
+        let detected = DetectBaseBranchUseCase::new(&self.git_repository).execute()?;
+        eprintln!(
+            "Comparing against {} ({})",
+            detected.branch, detected.source
+        );
+        Ok(detected.branch)
+    }
+}
+
+/// Writes commit warnings to stderr so that stdout stays machine-readable.
+fn report_warnings(commits: &[CommitDto]) {
+    for commit in commits {
+        for warning in &commit.warnings {
+            eprintln!(
+                "Warning: {} {}: {}",
+                &commit.hash[..7],
+                commit.message,
+                warning.message
+            );
+        }
+    }
+}
+
+/// Notes on stderr how many changed files were left out, so a short list
+/// is not mistaken for the whole change.
+fn report_hidden(hidden: &HiddenFilesDto) {
+    let mut reasons = Vec::new();
+    if hidden.excluded > 0 {
+        reasons.push(format!("{} excluded", hidden.excluded));
+    }
+    if hidden.generated > 0 {
+        reasons.push(format!(
+            "{} generated (list them with --show-generated)",
+            hidden.generated
+        ));
+    }
+    if !reasons.is_empty() {
+        eprintln!("Hidden files: {}", reasons.join(", "));
     }
 }
 
@@ -91,4 +506,15 @@ impl CommandController<GitRepositoryImpl> {
         let git_repository = GitRepositoryImpl::open_current_dir()?;
         Ok(Self::new(git_repository))
     }
+
+    /// The arlon config files layered over the repository's git config.
+    /// Unusable git config values are reported on stderr and ignored.
+    pub fn load_config(&self) -> Result<ConfigFile, CommandError> {
+        let config = ConfigLoader::load(self.git_repository.workdir())?;
+        let (git_config, errors) = Settings::lenient_git_config(self.git_repository.git_config());
+        for (key, error) in errors {
+            eprintln!("Warning: Ignoring {} from git config: {}", key, error);
+        }
+        Ok(config.or(git_config))
+    }
 }
@@ -0,0 +1,39 @@
+use crate::cli::{CommandError, CompletionShell};
+use arlon_core::GitRepositoryImpl;
+use clap_complete::env::{Bash, EnvCompleter, Fish, Powershell, Zsh};
+use clap_complete::CompletionCandidate;
+use std::io;
+
+const COMPLETE_VAR: &str = "COMPLETE";
+const BIN_NAME: &str = "arlon";
+
+pub fn print_registration(shell: CompletionShell) -> Result<(), CommandError> {
+    let completer: &dyn EnvCompleter = match shell {
+        CompletionShell::Bash => &Bash,
+        CompletionShell::Zsh => &Zsh,
+        CompletionShell::Fish => &Fish,
+        CompletionShell::Powershell => &Powershell,
+    };
+
+    completer
+        .write_registration(
+            COMPLETE_VAR,
+            BIN_NAME,
+            BIN_NAME,
+            BIN_NAME,
+            &mut io::stdout(),
+        )
+        .map_err(|e| CommandError::Output(format!("Failed to write completions: {}", e)))
+}
+
+pub fn branch_candidates() -> Vec<CompletionCandidate> {
+    let Ok(git_repository) = GitRepositoryImpl::open_current_dir() else {
+        return Vec::new();
+    };
+
+    git_repository
+        .ref_names()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
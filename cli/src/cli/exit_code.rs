@@ -0,0 +1,187 @@
+use crate::cli::CommandError;
+use arlon_core::application::use_cases::{
+    compare_authors, compare_commits, compare_files, compare_issues, detect_affected_packages,
+    detect_base_branch, generate_changelog, lint_commits,
+};
+use arlon_core::GitRepositoryError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    Success = 0,
+    DifferencesFound = 1,
+    Usage = 2,
+    BranchNotFound = 3,
+    NotARepository = 4,
+    Internal = 5,
+}
+
+impl ExitCode {
+    pub const ALL: [ExitCode; 6] = [
+        Self::Success,
+        Self::DifferencesFound,
+        Self::Usage,
+        Self::BranchNotFound,
+        Self::NotARepository,
+        Self::Internal,
+    ];
+
+    pub fn for_differences(exit_code: bool, has_differences: bool) -> Self {
+        if exit_code && has_differences {
+            Self::DifferencesFound
+        } else {
+            Self::Success
+        }
+    }
+
+    pub fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Success => "Success; with --exit-code, no differences were found",
+            Self::DifferencesFound => {
+                "Differences or violations were found (with --exit-code, lint, or --require-*)"
+            }
+            Self::Usage => "Invalid arguments or branch name",
+            Self::BranchNotFound => "Branch not found",
+            Self::NotARepository => "Not a Git repository",
+            Self::Internal => "Internal error",
+        }
+    }
+}
+
+impl From<&GitRepositoryError> for ExitCode {
+    fn from(error: &GitRepositoryError) -> Self {
+        match error {
+            GitRepositoryError::BranchNotFound { .. } => Self::BranchNotFound,
+            GitRepositoryError::RepositoryNotFound => Self::NotARepository,
+            GitRepositoryError::GitOperationFailed { .. } | GitRepositoryError::IoError(_) => {
+                Self::Internal
+            }
+        }
+    }
+}
+
+impl From<&CommandError> for ExitCode {
+    fn from(error: &CommandError) -> Self {
+        match error {
+            CommandError::CompareCommits(
+                compare_commits::CompareCommitsError::InvalidBranchName(_),
+            )
+            | CommandError::CompareAuthors(
+                compare_authors::CompareAuthorsError::InvalidBranchName(_),
+            )
+            | CommandError::CompareFiles(compare_files::CompareFilesError::InvalidBranchName(_))
+            | CommandError::CompareIssues(compare_issues::CompareIssuesError::InvalidBranchName(
+                _,
+            ))
+            | CommandError::DetectAffectedPackages(
+                detect_affected_packages::DetectAffectedPackagesError::InvalidBranchName(_),
+            )
+            | CommandError::GenerateChangelog(
+                generate_changelog::GenerateChangelogError::InvalidBranchName(_),
+            )
+            | CommandError::LintCommits(lint_commits::LintCommitsError::InvalidBranchName(_)) => {
+                Self::Usage
+            }
+            CommandError::CompareCommits(
+                compare_commits::CompareCommitsError::RepositoryError(e),
+            )
+            | CommandError::CompareAuthors(
+                compare_authors::CompareAuthorsError::RepositoryError(e),
+            )
+            | CommandError::CompareFiles(compare_files::CompareFilesError::RepositoryError(e))
+            | CommandError::CompareIssues(compare_issues::CompareIssuesError::RepositoryError(e))
+            | CommandError::DetectAffectedPackages(
+                detect_affected_packages::DetectAffectedPackagesError::RepositoryError(e),
+            )
+            | CommandError::GenerateChangelog(
+                generate_changelog::GenerateChangelogError::RepositoryError(e),
+            )
+            | CommandError::LintCommits(lint_commits::LintCommitsError::RepositoryError(e))
+            | CommandError::DetectBaseBranch(
+                detect_base_branch::DetectBaseBranchError::RepositoryError(e),
+            )
+            | CommandError::Repository(e) => Self::from(e),
+            CommandError::DetectBaseBranch(
+                detect_base_branch::DetectBaseBranchError::NotDetected,
+            )
+            | CommandError::CompareCommits(compare_commits::CompareCommitsError::InvalidTrailer(
+                _,
+            ))
+            | CommandError::CompareFiles(
+                compare_files::CompareFilesError::CodeOwnersNotFound
+                | compare_files::CompareFilesError::InvalidIgnoreFile(_),
+            )
+            | CommandError::DetectAffectedPackages(
+                detect_affected_packages::DetectAffectedPackagesError::InvalidWorkspace(_)
+                | detect_affected_packages::DetectAffectedPackagesError::WorkspaceNotFound,
+            ) => Self::Usage,
+            CommandError::InvalidBranchName(_)
+            | CommandError::Config(_)
+            | CommandError::Settings(_) => Self::Usage,
+            CommandError::DetectAffectedPackages(
+                detect_affected_packages::DetectAffectedPackagesError::InvalidManifestPath(_),
+            )
+            | CommandError::Output(_) => Self::Internal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arlon_core::BranchName;
+
+    #[test]
+    fn test_for_differences() {
+        assert_eq!(
+            ExitCode::for_differences(true, true),
+            ExitCode::DifferencesFound
+        );
+        assert_eq!(ExitCode::for_differences(true, false), ExitCode::Success);
+        assert_eq!(ExitCode::for_differences(false, true), ExitCode::Success);
+    }
+
+    #[test]
+    fn test_branch_not_found() {
+        let error =
+            CommandError::CompareCommits(compare_commits::CompareCommitsError::RepositoryError(
+                GitRepositoryError::BranchNotFound {
+                    branch: "main".to_string(),
+                    suggestions: vec![],
+                },
+            ));
+        assert_eq!(ExitCode::from(&error), ExitCode::BranchNotFound);
+    }
+
+    #[test]
+    fn test_not_a_repository() {
+        let error = CommandError::Repository(GitRepositoryError::RepositoryNotFound);
+        assert_eq!(ExitCode::from(&error), ExitCode::NotARepository);
+    }
+
+    #[test]
+    fn test_invalid_branch_name() {
+        let error =
+            CommandError::CompareFiles(compare_files::CompareFilesError::InvalidBranchName(
+                BranchName::new("".to_string()).unwrap_err(),
+            ));
+        assert_eq!(ExitCode::from(&error), ExitCode::Usage);
+    }
+
+    #[test]
+    fn test_base_branch_not_detected() {
+        let error =
+            CommandError::DetectBaseBranch(detect_base_branch::DetectBaseBranchError::NotDetected);
+        assert_eq!(ExitCode::from(&error), ExitCode::Usage);
+    }
+
+    #[test]
+    fn test_internal_error() {
+        let error = CommandError::Output("broken pipe".to_string());
+        assert_eq!(ExitCode::from(&error), ExitCode::Internal);
+        assert_eq!(ExitCode::Internal.as_i32(), 5);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::cli::{Args, ExitCode};
+use clap::{Command, CommandFactory};
+use std::fmt::Write;
+
+pub struct Example {
+    pub command: &'static str,
+    pub description: &'static str,
+}
+
+const ROOT_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon commits main",
+        description: "Show commits in HEAD that are not in main",
+    },
+    Example {
+        command: "arlon files main --format json",
+        description: "List files that differ from main as JSON",
+    },
+];
+
+const COMMITS_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon commits main",
+        description: "Show commits in HEAD that are not in main",
+    },
+    Example {
+        command: "arlon commits release/v1.0 --format json",
+        description: "Export the commits for release notes as JSON",
+    },
+    Example {
+        command: "arlon commits main --exit-code",
+        description: "Fail when HEAD contains commits that are not in main",
+    },
+    Example {
+        command: "arlon commits main --name-status",
+        description: "Show the files each commit changed, like git log --name-status",
+    },
+    Example {
+        command: "arlon commits main -p --word-diff",
+        description: "Show each commit's changes with changed words marked inline",
+    },
+    Example {
+        command: "arlon commits main --verify-signatures --require-signed",
+        description: "Fail when any commit lacks a good signature from a trusted key",
+    },
+];
+
+const FILES_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon files main",
+        description: "List files that differ between HEAD and main",
+    },
+    Example {
+        command: "arlon files main --dirstat=1",
+        description: "Show how the changes are spread over top-level directories",
+    },
+    Example {
+        command: "arlon files main --patch -U1",
+        description: "Show the changes to each file with one line of context",
+    },
+    Example {
+        command: "arlon files main -w --ignore-cr-at-eol",
+        description: "Mark files that were only reformatted or converted to CRLF",
+    },
+    Example {
+        command: "arlon files main --exclude '*.lock' --show-generated",
+        description: "Hide lockfiles but list generated files, tagged as such",
+    },
+    Example {
+        command: "arlon files main --recurse-submodules",
+        description: "Show the commits behind each moved submodule pointer",
+    },
+    Example {
+        command: "arlon files main --by-owner",
+        description: "Group changed files by their owners in CODEOWNERS",
+    },
+    Example {
+        command: "arlon files develop --exit-code",
+        description: "Fail when any file differs from develop",
+    },
+];
+
+const AUTHORS_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon authors release/v1.0",
+        description: "List everyone with commits since release/v1.0",
+    },
+    Example {
+        command: "arlon authors main --lines --format json",
+        description: "Export commit and line counts per author as JSON",
+    },
+];
+
+const ISSUES_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon issues main",
+        description: "List the issues referenced since main with their commits",
+    },
+    Example {
+        command: "arlon issues main --format json | jq -r '.items[].issue'",
+        description: "Print only the issue keys, e.g. for release notes",
+    },
+];
+
+const LINT_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon lint main",
+        description: "Check every commit message on the branch; exits with 1 on violations",
+    },
+    Example {
+        command: "arlon lint origin/main --format json",
+        description: "Report violations as JSON for a CI annotation step",
+    },
+];
+
+const AFFECTED_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon affected main",
+        description: "List the packages touched since main, one name per line",
+    },
+    Example {
+        command: "arlon affected main | xargs -I{} cargo test -p {}",
+        description: "Test only the affected Cargo packages",
+    },
+];
+
+const CHANGELOG_EXAMPLES: &[Example] = &[
+    Example {
+        command: "arlon changelog v1.2.0 > CHANGES.md",
+        description: "Write the changes since the v1.2.0 tag as Markdown",
+    },
+    Example {
+        command: "arlon changelog v1.2.0 release/1.3 --format json",
+        description: "Changes between two refs, with the suggested bump, as JSON",
+    },
+];
+
+const COMPLETIONS_EXAMPLES: &[Example] = &[Example {
+    command: "source <(arlon completions bash)",
+    description: "Enable completions in the current bash session",
+}];
+
+pub fn examples(name: &str) -> &'static [Example] {
+    match name {
+        "arlon" => ROOT_EXAMPLES,
+        "commits" => COMMITS_EXAMPLES,
+        "files" => FILES_EXAMPLES,
+        "authors" => AUTHORS_EXAMPLES,
+        "issues" => ISSUES_EXAMPLES,
+        "lint" => LINT_EXAMPLES,
+        "affected" => AFFECTED_EXAMPLES,
+        "changelog" => CHANGELOG_EXAMPLES,
+        "completions" => COMPLETIONS_EXAMPLES,
+        _ => &[],
+    }
+}
+
+/// `Args::command()` with examples and exit statuses appended to `--help`.
+pub fn command() -> Command {
+    with_after_help(Args::command())
+}
+
+fn with_after_help(command: Command) -> Command {
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    let after_help = after_help(command.get_name());
+
+    let mut command = match after_help {
+        Some(text) => command.after_long_help(text),
+        None => command,
+    };
+    for name in names {
+        command = command.mut_subcommand(name, with_after_help);
+    }
+    command
+}
+
+fn after_help(name: &str) -> Option<String> {
+    let examples = examples(name);
+    if examples.is_empty() {
+        return None;
+    }
+
+    let mut text = String::from("Examples:\n");
+    for example in examples {
+        let _ = writeln!(text, "  {}\n      {}", example.command, example.description);
+    }
+
+    text.push_str("\nExit status:\n");
+    for code in ExitCode::ALL {
+        let _ = writeln!(text, "  {}  {}", code.as_i32(), code.description());
+    }
+
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_has_examples() {
+        let mut command = command();
+        let help = command.render_long_help().to_string();
+        assert!(help.contains("arlon commits main"));
+        assert!(help.contains("Exit status:"));
+
+        let commits = command.find_subcommand_mut("commits").unwrap();
+        let help = commits.render_long_help().to_string();
+        assert!(help.contains("--exit-code"));
+        assert!(help.contains("arlon commits release/v1.0 --format json"));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::cli::{help, CommandError, ExitCode};
+use clap::Command;
+use clap_mangen::roff::{bold, roman, Roff};
+use clap_mangen::Man;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+pub fn generate(out_dir: &Path) -> Result<(), CommandError> {
+    let mut command = help::command().disable_help_subcommand(true);
+    command.build();
+
+    fs::create_dir_all(out_dir).map_err(output_error)?;
+
+    write_page(&command, out_dir)?;
+    for subcommand in command.get_subcommands().filter(|s| !s.is_hide_set()) {
+        write_page(subcommand, out_dir)?;
+    }
+
+    Ok(())
+}
+
+fn write_page(command: &Command, out_dir: &Path) -> Result<(), CommandError> {
+    let man = Man::new(command.clone())
+        .source(format!("arlon {}", env!("CARGO_PKG_VERSION")))
+        .manual("Arlon Manual");
+    let path = out_dir.join(man.get_filename());
+    let mut file = fs::File::create(&path).map_err(output_error)?;
+
+    render(&man, command, &mut file).map_err(output_error)?;
+    eprintln!("Wrote {}", path.display());
+
+    Ok(())
+}
+
+fn render(man: &Man, command: &Command, w: &mut dyn Write) -> std::io::Result<()> {
+    man.render_title(w)?;
+    man.render_name_section(w)?;
+    man.render_synopsis_section(w)?;
+    man.render_description_section(w)?;
+    man.render_options_section(w)?;
+    if command.has_subcommands() {
+        man.render_subcommands_section(w)?;
+    }
+
+    let mut roff = Roff::new();
+    render_examples(&mut roff, command.get_name());
+    render_exit_status(&mut roff);
+    roff.to_writer(w)?;
+
+    if command.get_version().is_some() {
+        man.render_version_section(w)?;
+    }
+
+    Ok(())
+}
+
+fn render_examples(roff: &mut Roff, name: &str) {
+    let examples = help::examples(name);
+    if examples.is_empty() {
+        return;
+    }
+
+    roff.control("SH", ["EXAMPLES"]);
+    for example in examples {
+        roff.control("TP", []);
+        roff.text([bold(example.command)]);
+        roff.text([roman(example.description)]);
+    }
+}
+
+fn render_exit_status(roff: &mut Roff) {
+    roff.control("SH", ["EXIT STATUS"]);
+    for code in ExitCode::ALL {
+        roff.control("TP", []);
+        roff.text([bold(code.as_i32().to_string())]);
+        roff.text([roman(code.description())]);
+    }
+}
+
+fn output_error(e: std::io::Error) -> CommandError {
+    CommandError::Output(format!("Failed to write man page: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let out_dir = tempfile::tempdir().unwrap();
+
+        generate(out_dir.path()).unwrap();
+
+        let page = fs::read_to_string(out_dir.path().join("arlon-commits.1")).unwrap();
+        assert!(page.contains(".SH EXAMPLES"));
+        assert!(page.contains(".SH \"EXIT STATUS\""));
+        assert!(page.contains("exit\\-code"));
+        assert!(out_dir.path().join("arlon.1").exists());
+        assert!(out_dir.path().join("arlon-files.1").exists());
+        assert!(!out_dir.path().join("arlon-man.1").exists());
+    }
+}
@@ -1,5 +1,12 @@
 pub mod args;
 pub mod commands;
+pub mod completions;
+pub mod exit_code;
+pub mod help;
+pub mod man;
+pub mod settings;
 
-pub use args::{Args, Commands, OutputFormat};
-pub use commands::CommandController;
+pub use args::{Args, Commands, CompletionShell, OutputFormat};
+pub use commands::{CommandController, CommandError};
+pub use exit_code::ExitCode;
+pub use settings::Settings;
@@ -0,0 +1,398 @@
+use crate::cli::OutputFormat;
+use arlon_core::domain::value_objects::{
+    DateFormatError, ExcludePatternsError, IssuePatternsError, LintRulesError, PackageGlobsError,
+    RenameDetectionError, TextEncodingError,
+};
+use arlon_core::{
+    ConfigFile, DateFormat, ExcludePatterns, FileComparisonOptions, IssuePatterns, LintRules,
+    PackageGlobs, RenameDetection, SignatureVerification, TextEncoding, WhitespaceOptions,
+};
+use clap::ValueEnum;
+use std::ops::RangeInclusive;
+
+/// Hash lengths accepted for `abbrev`, as in `git log --abbrev`.
+const ABBREV_RANGE: RangeInclusive<usize> = 4..=40;
+
+/// Options for a comparison after layering command-line flags, the project
+/// config, the user config and git config (in that order of precedence)
+/// over defaults.
+#[derive(Debug)]
+pub struct Settings {
+    /// `None` when neither the command line nor a config file names a base
+    /// branch, in which case it is detected from the repository.
+    pub base: Option<String>,
+    pub format: OutputFormat,
+    pub exit_code: bool,
+    pub date_format: DateFormat,
+    pub file_options: FileComparisonOptions,
+    pub excludes: ExcludePatterns,
+    pub abbrev: Option<usize>,
+    pub encoding: TextEncoding,
+    pub packages: PackageGlobs,
+    pub issue_patterns: IssuePatterns,
+    pub lint_rules: LintRules,
+    /// Keys to check signatures against with `--verify-signatures`.
+    pub signature_verification: SignatureVerification,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SettingsError {
+    #[error("Invalid output format: {0}")]
+    InvalidFormat(String),
+    #[error(
+        "Hash length must be between {} and {}: {0}",
+        ABBREV_RANGE.start(),
+        ABBREV_RANGE.end()
+    )]
+    InvalidAbbrev(usize),
+    #[error(transparent)]
+    DateFormat(#[from] DateFormatError),
+    #[error(transparent)]
+    RenameDetection(#[from] RenameDetectionError),
+    #[error(transparent)]
+    ExcludePatterns(#[from] ExcludePatternsError),
+    #[error(transparent)]
+    Encoding(#[from] TextEncodingError),
+    #[error(transparent)]
+    PackageGlobs(#[from] PackageGlobsError),
+    #[error(transparent)]
+    IssuePatterns(#[from] IssuePatternsError),
+    #[error(transparent)]
+    LintRules(#[from] LintRulesError),
+}
+
+impl SettingsError {
+    pub fn kind(&self) -> &'static str {
+        "invalid_option"
+    }
+}
+
+impl Settings {
+    pub fn resolve(
+        overrides: ConfigFile,
+        config: ConfigFile,
+        exit_code: bool,
+    ) -> Result<Self, SettingsError> {
+        let resolved = overrides.or(config);
+
+        let format = match resolved.format {
+            Some(format) => OutputFormat::from_str(&format, true)
+                .map_err(|_| SettingsError::InvalidFormat(format))?,
+            None => OutputFormat::Simple,
+        };
+
+        let date_format = match resolved.date_format {
+            Some(date_format) => DateFormat::new(date_format)?,
+            None => DateFormat::default(),
+        };
+
+        let renames_config = resolved.renames.unwrap_or_default();
+        let renames = match renames_config.enabled {
+            Some(true) => RenameDetection::enabled(
+                renames_config
+                    .threshold
+                    .unwrap_or(RenameDetection::DEFAULT_THRESHOLD),
+            )?,
+            Some(false) | None => RenameDetection::Disabled,
+        };
+
+        let excludes = ExcludePatterns::new(resolved.exclude.unwrap_or_default())?;
+
+        let abbrev = match resolved.abbrev {
+            Some(abbrev) if !ABBREV_RANGE.contains(&abbrev) => {
+                return Err(SettingsError::InvalidAbbrev(abbrev));
+            }
+            abbrev => abbrev,
+        };
+
+        let encoding = match resolved.encoding {
+            Some(encoding) => TextEncoding::new(&encoding)?,
+            None => TextEncoding::default(),
+        };
+
+        let packages = PackageGlobs::new(resolved.packages.unwrap_or_default())?;
+
+        let issue_patterns = match resolved.issue_patterns {
+            Some(patterns) => IssuePatterns::new(patterns)?,
+            None => IssuePatterns::default(),
+        };
+
+        let lint_config = resolved.lint.unwrap_or_default();
+        let lint_rules = LintRules::new(
+            lint_config.rules,
+            lint_config
+                .max_subject_length
+                .unwrap_or(LintRules::DEFAULT_MAX_SUBJECT_LENGTH),
+        )?;
+
+        let signing_config = resolved.signing.unwrap_or_default();
+        let signature_verification = SignatureVerification {
+            gpg_keyring: signing_config.gpg_keyring,
+            allowed_signers: signing_config.allowed_signers,
+        };
+
+        Ok(Self {
+            base: resolved.base,
+            format,
+            exit_code,
+            date_format,
+            file_options: FileComparisonOptions {
+                renames,
+                rename_limit: renames_config.limit,
+                ..FileComparisonOptions::default()
+            },
+            excludes,
+            abbrev,
+            encoding,
+            packages,
+            issue_patterns,
+            lint_rules,
+            signature_verification,
+        })
+    }
+
+    /// Drops the values in the git config layer that arlon cannot use,
+    /// returning each git key with why, so that a setting meant for git does
+    /// not fail every command. The defaults are used instead.
+    pub fn lenient_git_config(
+        mut git_config: ConfigFile,
+    ) -> (ConfigFile, Vec<(&'static str, SettingsError)>) {
+        let mut errors = Vec::new();
+
+        if let Some(Err(e)) = git_config.date_format.clone().map(DateFormat::new) {
+            git_config.date_format = None;
+            errors.push(("log.date", e.into()));
+        }
+        if let Some(abbrev) = git_config
+            .abbrev
+            .filter(|abbrev| !ABBREV_RANGE.contains(abbrev))
+        {
+            git_config.abbrev = None;
+            errors.push(("core.abbrev", SettingsError::InvalidAbbrev(abbrev)));
+        }
+        if let Some(Err(e)) = git_config.encoding.as_deref().map(TextEncoding::new) {
+            git_config.encoding = None;
+            errors.push(("i18n.logOutputEncoding", e.into()));
+        }
+
+        (git_config, errors)
+    }
+
+    /// Ignores the given whitespace when comparing files.
+    pub fn with_whitespace(mut self, whitespace: WhitespaceOptions) -> Self {
+        self.file_options.whitespace = whitespace;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arlon_core::infra::config::{LintConfig, RenamesConfig};
+
+    fn config(base: &str, format: &str) -> ConfigFile {
+        ConfigFile {
+            base: Some(base.to_string()),
+            format: Some(format.to_string()),
+            ..ConfigFile::default()
+        }
+    }
+
+    #[test]
+    fn test_lenient_git_config() {
+        let (git_config, errors) = Settings::lenient_git_config(ConfigFile {
+            date_format: Some("%Y-%Q".to_string()),
+            abbrev: Some(2),
+            encoding: Some("klingon".to_string()),
+            exclude: Some(vec!["*.lock".to_string()]),
+            ..ConfigFile::default()
+        });
+        let keys: Vec<&str> = errors.iter().map(|(key, _)| *key).collect();
+        assert_eq!(
+            keys,
+            vec!["log.date", "core.abbrev", "i18n.logOutputEncoding"]
+        );
+        assert!(matches!(
+            errors[0].1,
+            SettingsError::DateFormat(DateFormatError::InvalidFormat(_))
+        ));
+        assert!(matches!(errors[1].1, SettingsError::InvalidAbbrev(2)));
+        assert!(matches!(errors[2].1, SettingsError::Encoding(_)));
+        assert_eq!(git_config.date_format, None);
+        assert_eq!(git_config.abbrev, None);
+        assert_eq!(git_config.encoding, None);
+        assert_eq!(git_config.exclude, Some(vec!["*.lock".to_string()]));
+
+        let settings = Settings::resolve(config("main", "simple"), git_config, false).unwrap();
+        assert_eq!(settings.date_format, DateFormat::default());
+        assert_eq!(settings.abbrev, None);
+        assert!(settings.encoding.is_utf8());
+
+        let (git_config, errors) = Settings::lenient_git_config(ConfigFile {
+            date_format: Some("%d/%m/%Y".to_string()),
+            abbrev: Some(12),
+            encoding: Some("ISO-8859-1".to_string()),
+            ..ConfigFile::default()
+        });
+        assert!(errors.is_empty());
+        assert_eq!(git_config.date_format.as_deref(), Some("%d/%m/%Y"));
+        assert_eq!(git_config.abbrev, Some(12));
+        assert_eq!(git_config.encoding.as_deref(), Some("ISO-8859-1"));
+    }
+
+    #[test]
+    fn test_defaults() {
+        let settings = Settings::resolve(
+            ConfigFile {
+                base: Some("main".to_string()),
+                ..ConfigFile::default()
+            },
+            ConfigFile::default(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(settings.base.as_deref(), Some("main"));
+        assert_eq!(settings.format, OutputFormat::Simple);
+        assert_eq!(settings.date_format, DateFormat::default());
+        assert_eq!(settings.file_options, FileComparisonOptions::default());
+        assert!(settings.excludes.is_empty());
+        assert_eq!(settings.abbrev, None);
+        assert!(settings.encoding.is_utf8());
+        assert!(settings.packages.is_empty());
+        assert_eq!(settings.lint_rules, LintRules::default());
+        assert_eq!(
+            settings.signature_verification,
+            SignatureVerification::default()
+        );
+    }
+
+    #[test]
+    fn test_precedence_cli_over_project_over_user() {
+        let cli = ConfigFile {
+            base: Some("release".to_string()),
+            ..ConfigFile::default()
+        };
+        let project = ConfigFile {
+            date_format: Some("%Y".to_string()),
+            ..config("develop", "json")
+        };
+        let user = ConfigFile {
+            date_format: Some("%m".to_string()),
+            exclude: Some(vec!["Cargo.lock".to_string()]),
+            ..config("main", "simple")
+        };
+
+        let settings = Settings::resolve(cli, project.or(user), true).unwrap();
+
+        assert_eq!(settings.base.as_deref(), Some("release"));
+        assert_eq!(settings.format, OutputFormat::Json);
+        assert_eq!(settings.date_format.as_str(), "%Y");
+        assert_eq!(settings.excludes.patterns(), ["Cargo.lock"]);
+        assert!(settings.exit_code);
+    }
+
+    #[test]
+    fn test_cli_disables_configured_renames() {
+        let cli = ConfigFile {
+            renames: Some(RenamesConfig {
+                enabled: Some(false),
+                threshold: None,
+                limit: None,
+            }),
+            ..config("main", "simple")
+        };
+        let project = ConfigFile {
+            renames: Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(70),
+                limit: Some(400),
+            }),
+            ..ConfigFile::default()
+        };
+
+        let settings = Settings::resolve(cli.clone(), project.clone(), false).unwrap();
+        assert_eq!(settings.file_options.renames, RenameDetection::Disabled);
+
+        let settings = Settings::resolve(config("main", "simple"), project, false).unwrap();
+        assert_eq!(
+            settings.file_options.renames,
+            RenameDetection::Enabled { threshold: 70 }
+        );
+        assert_eq!(settings.file_options.rename_limit, Some(400));
+    }
+
+    #[test]
+    fn test_base_left_for_detection() {
+        let settings =
+            Settings::resolve(ConfigFile::default(), ConfigFile::default(), false).unwrap();
+        assert!(settings.base.is_none());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let result = Settings::resolve(config("main", "xml"), ConfigFile::default(), false);
+        assert!(matches!(
+            result.unwrap_err(),
+            SettingsError::InvalidFormat(_)
+        ));
+
+        let result = Settings::resolve(
+            ConfigFile {
+                date_format: Some("%Q".to_string()),
+                ..config("main", "simple")
+            },
+            ConfigFile::default(),
+            false,
+        );
+        assert!(matches!(result.unwrap_err(), SettingsError::DateFormat(_)));
+
+        let result = Settings::resolve(
+            ConfigFile {
+                abbrev: Some(3),
+                ..config("main", "simple")
+            },
+            ConfigFile::default(),
+            false,
+        );
+        let error = result.unwrap_err();
+        assert!(matches!(error, SettingsError::InvalidAbbrev(3)));
+        assert_eq!(error.to_string(), "Hash length must be between 4 and 40: 3");
+
+        let result = Settings::resolve(
+            ConfigFile {
+                encoding: Some("klingon".to_string()),
+                ..config("main", "simple")
+            },
+            ConfigFile::default(),
+            false,
+        );
+        assert!(matches!(result.unwrap_err(), SettingsError::Encoding(_)));
+
+        let result = Settings::resolve(
+            ConfigFile {
+                issue_patterns: Some(vec!["(".to_string()]),
+                ..config("main", "simple")
+            },
+            ConfigFile::default(),
+            false,
+        );
+        assert!(matches!(
+            result.unwrap_err(),
+            SettingsError::IssuePatterns(_)
+        ));
+
+        let result = Settings::resolve(
+            ConfigFile {
+                lint: Some(LintConfig {
+                    rules: Some(vec!["no-typos".to_string()]),
+                    max_subject_length: None,
+                }),
+                ..config("main", "simple")
+            },
+            ConfigFile::default(),
+            false,
+        );
+        assert!(matches!(result.unwrap_err(), SettingsError::LintRules(_)));
+    }
+}
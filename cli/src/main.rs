@@ -1,20 +1,152 @@
-use clap::Parser;
-use cli::{Args, CommandController};
+use arlon_core::{ConfigFile, GitRepositoryImpl};
+use arlon_core::{ErrorDto, ErrorOutputDto, JsonFormatter};
+use clap::{FromArgMatches, ValueEnum};
+use clap_complete::CompleteEnv;
+use cli::{
+    completions, help, man, Args, CommandController, CommandError, Commands, ExitCode,
+    OutputFormat, Settings,
+};
 use std::process;
 
 mod cli;
 
 fn main() {
-    let args = Args::parse();
+    CompleteEnv::with_factory(help::command).complete();
 
-    if let Err(e) = run(args) {
-        eprintln!("Error: {}", e);
-        process::exit(1);
+    let args = Args::from_arg_matches(&help::command().get_matches()).unwrap_or_else(|e| e.exit());
+    let mut format = args
+        .command
+        .format()
+        .cloned()
+        .unwrap_or(OutputFormat::Simple);
+
+    match run(args, &mut format) {
+        Ok(code) => process::exit(code.as_i32()),
+        Err(e) => {
+            report_error(&e, &format);
+            process::exit(ExitCode::from(&e).as_i32());
+        }
+    }
+}
+
+fn report_error(error: &CommandError, format: &OutputFormat) {
+    match format {
+        OutputFormat::Simple => eprintln!("Error: {}", error),
+        OutputFormat::Json => {
+            let output = ErrorOutputDto::from(ErrorDto::from(error));
+            if let Err(e) = JsonFormatter.format_error(&output) {
+                eprintln!("Error: {}", e);
+            }
+        }
+    }
+}
+
+fn run(args: Args, format: &mut OutputFormat) -> Result<ExitCode, CommandError> {
+    match args.command {
+        Commands::Commits(commits) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(
+                &controller,
+                commits.overrides(),
+                commits.compare.exit_code,
+                format,
+            )?
+            .with_whitespace(commits.whitespace.options());
+            controller.compare_commits(
+                &settings,
+                commits.require_trailer,
+                commits.verify_signatures,
+                commits.require_signed,
+                commits.name_status,
+                commits.patch.options(),
+            )
+        }
+        Commands::Files(files) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(
+                &controller,
+                files.overrides(),
+                files.compare.exit_code,
+                format,
+            )?
+            .with_whitespace(files.whitespace.options());
+            match files.dirstat {
+                Some(depth) => {
+                    controller.compare_dirstat(&settings, usize::from(depth), files.show_generated)
+                }
+                None if files.by_owner => {
+                    controller.compare_files_by_owner(&settings, files.show_generated)
+                }
+                None => controller.compare_files(
+                    &settings,
+                    files.owners,
+                    files.show_generated,
+                    files.recurse_submodules,
+                    files.patch.options(),
+                ),
+            }
+        }
+        Commands::Authors(authors) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(
+                &controller,
+                authors.overrides(),
+                authors.compare.exit_code,
+                format,
+            )?;
+            controller.compare_authors(&settings, authors.lines)
+        }
+        Commands::Issues(issues) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(
+                &controller,
+                issues.overrides(),
+                issues.compare.exit_code,
+                format,
+            )?;
+            controller.compare_issues(&settings)
+        }
+        Commands::Lint(lint) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(&controller, lint.overrides(), false, format)?;
+            controller.lint_commits(&settings)
+        }
+        Commands::Affected(affected) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(
+                &controller,
+                affected.overrides(),
+                affected.compare.exit_code,
+                format,
+            )?;
+            controller.detect_affected_packages(&settings)
+        }
+        Commands::Changelog(changelog) => {
+            let controller = CommandController::new_with_current_dir()?;
+            let settings = resolve_settings(&controller, changelog.overrides(), false, format)?;
+            controller.generate_changelog(&settings, changelog.to)
+        }
+        Commands::Completions { shell } => {
+            completions::print_registration(shell).map(|()| ExitCode::Success)
+        }
+        Commands::Man { out_dir } => man::generate(&out_dir).map(|()| ExitCode::Success),
     }
 }
 
-fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
-    let controller = CommandController::new_with_current_dir()?;
-    controller.execute(args.command)?;
-    Ok(())
+fn resolve_settings(
+    controller: &CommandController<GitRepositoryImpl>,
+    overrides: ConfigFile,
+    exit_code: bool,
+    format: &mut OutputFormat,
+) -> Result<Settings, CommandError> {
+    let config = controller.load_config()?;
+
+    // Report errors in the configured format even if resolution fails below.
+    if let Some(configured) = overrides.format.as_ref().or(config.format.as_ref()) {
+        if let Ok(configured) = OutputFormat::from_str(configured, true) {
+            *format = configured;
+        }
+    }
+
+    Ok(Settings::resolve(overrides, config, exit_code)?)
 }
@@ -0,0 +1,78 @@
+use crate::domain::entities::AuthorStats;
+use crate::domain::value_objects::DateFormat;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AuthorDto {
+    pub name: String,
+    pub email: String,
+    pub commits: usize,
+    pub first_commit_date: String,
+    pub last_commit_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines_added: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines_removed: Option<usize>,
+}
+
+impl AuthorDto {
+    pub fn from_author_stats(stats: AuthorStats, date_format: &DateFormat) -> Self {
+        let lines = stats.lines();
+        let (first_offset, last_offset) = stats.offsets_minutes();
+        Self {
+            name: stats.name().to_string(),
+            email: stats.email().to_string(),
+            commits: stats.commit_count(),
+            first_commit_date: date_format.format_timestamp(stats.first_timestamp(), first_offset),
+            last_commit_date: date_format.format_timestamp(stats.last_timestamp(), last_offset),
+            lines_added: lines.map(|lines| lines.added),
+            lines_removed: lines.map(|lines| lines.removed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Commit;
+    use crate::domain::value_objects::{CommitHash, LineStats};
+
+    fn create_test_stats(lines: Option<LineStats>) -> AuthorStats {
+        let commit = Commit::new(
+            CommitHash::new("abcdef1234567890abcdef1234567890abcdef12".to_string()).unwrap(),
+            "Test Author".to_string(),
+            "test@example.com".to_string(),
+            1634567890, // 2021-10-18 12:31:30 UTC
+            "Test commit message".to_string(),
+        );
+        AuthorStats::new(&commit, lines)
+    }
+
+    #[test]
+    fn test_author_dto_from_author_stats() {
+        let date_format = DateFormat::new("%Y-%m-%d".to_string()).unwrap();
+        let dto = AuthorDto::from_author_stats(
+            create_test_stats(Some(LineStats::new(12, 3))),
+            &date_format,
+        );
+
+        assert_eq!(dto.name, "Test Author");
+        assert_eq!(dto.email, "test@example.com");
+        assert_eq!(dto.commits, 1);
+        assert_eq!(dto.first_commit_date, "2021-10-18");
+        assert_eq!(dto.last_commit_date, "2021-10-18");
+        assert_eq!(dto.lines_added, Some(12));
+        assert_eq!(dto.lines_removed, Some(3));
+    }
+
+    #[test]
+    fn test_author_dto_serialization_without_lines() {
+        let dto = AuthorDto::from_author_stats(create_test_stats(None), &DateFormat::default());
+
+        let json: serde_json::Value = serde_json::to_value(&dto).unwrap();
+        assert_eq!(json["commits"], 1);
+        assert!(json.get("lines_added").is_none());
+        assert!(json.get("lines_removed").is_none());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::domain::entities::BaseBranchSource;
+use crate::domain::value_objects::BranchName;
+
+#[derive(Debug, Clone)]
+pub struct BaseBranchDto {
+    pub branch: String,
+    pub source: String,
+}
+
+impl BaseBranchDto {
+    pub fn new(branch: BranchName, source: BaseBranchSource) -> Self {
+        Self {
+            branch: branch.as_str().to_string(),
+            source: source.description().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_branch_dto_new() {
+        let branch = BranchName::new("origin/main".to_string()).unwrap();
+        let dto = BaseBranchDto::new(branch, BaseBranchSource::RemoteDefault);
+
+        assert_eq!(dto.branch, "origin/main");
+        assert_eq!(dto.source, "default branch of origin");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::application::dto::SCHEMA_VERSION;
+use crate::domain::entities::{Changelog, ChangelogEntry, ChangelogSection};
+use crate::domain::value_objects::ComparisonEndpoints;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct ChangelogEntryDto {
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct ChangelogSectionDto {
+    /// `Breaking Changes`, `Features` or `Fixes`.
+    pub title: String,
+    pub entries: Vec<ChangelogEntryDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct ChangelogDto {
+    /// Suggested SemVer bump: `major`, `minor`, `patch` or `none`.
+    pub bump: String,
+    /// The base with the bump applied, when the base is a version tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_version: Option<String>,
+    /// Non-empty sections, breaking changes first.
+    pub sections: Vec<ChangelogSectionDto>,
+}
+
+/// Top-level envelope for `arlon changelog` output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ChangelogOutputDto {
+    pub schema_version: u32,
+    pub base: String,
+    pub target: String,
+    /// Commit `base` resolved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_oid: Option<String>,
+    /// Commit `target` resolved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_oid: Option<String>,
+    /// Best common ancestor of the base and target, absent for unrelated
+    /// histories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_base: Option<String>,
+    #[serde(flatten)]
+    pub changelog: ChangelogDto,
+}
+
+impl From<&ChangelogEntry> for ChangelogEntryDto {
+    fn from(entry: &ChangelogEntry) -> Self {
+        Self {
+            hash: entry.hash().to_string(),
+            scope: entry.scope().map(str::to_string),
+            description: entry.description().to_string(),
+        }
+    }
+}
+
+impl ChangelogDto {
+    /// `base` is the ref the changelog starts from, used to suggest the
+    /// next version.
+    pub fn from_changelog(changelog: &Changelog, base: &str) -> Self {
+        let sections = ChangelogSection::ALL
+            .iter()
+            .filter(|section| !changelog.entries(**section).is_empty())
+            .map(|section| ChangelogSectionDto {
+                title: section.title().to_string(),
+                entries: changelog
+                    .entries(*section)
+                    .iter()
+                    .map(ChangelogEntryDto::from)
+                    .collect(),
+            })
+            .collect();
+
+        Self {
+            bump: changelog.bump().as_str().to_string(),
+            next_version: changelog.bump().apply(base),
+            sections,
+        }
+    }
+}
+
+impl ChangelogOutputDto {
+    pub fn new(base: String, target: String, changelog: ChangelogDto) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            base,
+            target,
+            base_oid: None,
+            target_oid: None,
+            merge_base: None,
+            changelog,
+        }
+    }
+
+    /// Records the commits the changelog was generated between.
+    pub fn with_endpoints(mut self, endpoints: &ComparisonEndpoints) -> Self {
+        self.base_oid = Some(endpoints.base.to_string());
+        self.target_oid = Some(endpoints.target.to_string());
+        self.merge_base = endpoints.merge_base.as_ref().map(ToString::to_string);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CommitHash;
+
+    #[test]
+    fn test_from_changelog() {
+        let mut changelog = Changelog::default();
+        changelog.push(
+            ChangelogSection::Fixes,
+            ChangelogEntry::new(
+                CommitHash::new("a".repeat(40)).unwrap(),
+                Some("cli".to_string()),
+                "handle detached HEAD".to_string(),
+            ),
+        );
+
+        let dto = ChangelogDto::from_changelog(&changelog, "v1.2.3");
+
+        assert_eq!(dto.bump, "patch");
+        assert_eq!(dto.next_version.as_deref(), Some("v1.2.4"));
+        assert_eq!(dto.sections.len(), 1);
+        assert_eq!(dto.sections[0].title, "Fixes");
+        assert_eq!(dto.sections[0].entries[0].scope.as_deref(), Some("cli"));
+    }
+
+    #[test]
+    fn test_output_serialization() {
+        let changelog = ChangelogDto::from_changelog(&Changelog::default(), "main");
+        let output = ChangelogOutputDto::new("main".to_string(), "HEAD".to_string(), changelog);
+
+        let json: serde_json::Value = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["bump"], "none");
+        assert!(json.get("next_version").is_none());
+        assert_eq!(json["sections"], serde_json::json!([]));
+    }
+}
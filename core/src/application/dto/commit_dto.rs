@@ -1,31 +1,132 @@
+use crate::application::dto::FileDto;
 use crate::domain::entities::Commit;
+use crate::domain::value_objects::{CommitWarning, DateFormat, SignatureStatus, Trailer};
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct CommitDto {
     pub hash: String,
     pub author: String,
     pub email: String,
     pub date: String,
     pub message: String,
+    /// Issue references found in the commit message.
+    pub issues: Vec<String>,
+    /// Git trailers at the end of the commit message, in order.
+    pub trailers: Vec<TrailerDto>,
+    /// Required trailers the commit lacks, when checking for them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_trailers: Vec<String>,
+    /// Fixups and merges to clean up before merging the branch.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<CommitWarningDto>,
+    /// `unsigned` or `signed`, or once verified `good`, `bad` or
+    /// `unknown_key`.
+    pub signature: String,
+    /// `gpg`, `ssh` or `x509` for signed commits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_format: Option<String>,
+    /// Files changed relative to the first parent; present only when
+    /// requested with `--name-status` or `--patch`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<FileDto>>,
 }
 
-impl From<Commit> for CommitDto {
-    fn from(commit: Commit) -> Self {
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct TrailerDto {
+    pub token: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct CommitWarningDto {
+    /// `unsquashed_fixup` or `merge_from_base`.
+    pub kind: String,
+    pub message: String,
+    /// The fixup target or the merged base commit.
+    pub commit: String,
+}
+
+impl From<&CommitWarning> for CommitWarningDto {
+    fn from(warning: &CommitWarning) -> Self {
+        Self {
+            kind: warning.kind().to_string(),
+            message: warning.message(),
+            commit: warning.related_commit().to_string(),
+        }
+    }
+}
+
+impl From<&Trailer> for TrailerDto {
+    fn from(trailer: &Trailer) -> Self {
+        Self {
+            token: trailer.token().to_string(),
+            value: trailer.value().to_string(),
+        }
+    }
+}
+
+impl CommitDto {
+    pub fn from_commit(commit: Commit, date_format: &DateFormat) -> Self {
         Self {
             hash: commit.hash().to_string(),
             author: commit.author().to_string(),
             email: commit.email().to_string(),
-            date: commit.formatted_date(),
+            date: commit.format_date(date_format),
             message: commit.message().to_string(),
+            issues: Vec::new(),
+            trailers: commit.trailers().iter().map(TrailerDto::from).collect(),
+            missing_trailers: Vec::new(),
+            warnings: Vec::new(),
+            signature: match commit.signature() {
+                Some(_) => SignatureStatus::Signed,
+                None => SignatureStatus::Unsigned,
+            }
+            .as_str()
+            .to_string(),
+            signature_format: commit.signature().map(|format| format.as_str().to_string()),
+            files: None,
         }
     }
+
+    pub fn with_issues(mut self, issues: Vec<String>) -> Self {
+        self.issues = issues;
+        self
+    }
+
+    pub fn with_warnings(mut self, warnings: &[CommitWarning]) -> Self {
+        self.warnings = warnings.iter().map(CommitWarningDto::from).collect();
+        self
+    }
+
+    /// Replaces `signed` with the result of verifying the signature.
+    pub fn with_signature_status(mut self, status: SignatureStatus) -> Self {
+        self.signature = status.as_str().to_string();
+        self
+    }
+
+    pub fn with_files(mut self, files: Vec<FileDto>) -> Self {
+        self.files = Some(files);
+        self
+    }
+
+    pub fn with_missing_trailers(mut self, missing_trailers: Vec<String>) -> Self {
+        self.missing_trailers = missing_trailers;
+        self
+    }
+}
+
+impl From<Commit> for CommitDto {
+    fn from(commit: Commit) -> Self {
+        Self::from_commit(commit, &DateFormat::default())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::value_objects::CommitHash;
+    use crate::domain::value_objects::{CommitHash, SignatureFormat};
 
     fn create_test_commit() -> Commit {
         let hash = CommitHash::new("abcdef1234567890abcdef1234567890abcdef12".to_string()).unwrap();
@@ -48,6 +149,30 @@ mod tests {
         assert_eq!(dto.email, "test@example.com");
         assert!(dto.date.contains("2021-10-18"));
         assert_eq!(dto.message, "Test commit message");
+        assert_eq!(dto.signature, "unsigned");
+        assert_eq!(dto.signature_format, None);
+    }
+
+    #[test]
+    fn test_commit_dto_signature() {
+        let commit = create_test_commit().with_signature(SignatureFormat::Ssh);
+        let dto = CommitDto::from(commit);
+
+        assert_eq!(dto.signature, "signed");
+        assert_eq!(dto.signature_format.as_deref(), Some("ssh"));
+        assert_eq!(
+            dto.with_signature_status(SignatureStatus::Good).signature,
+            "good"
+        );
+    }
+
+    #[test]
+    fn test_commit_dto_from_commit_with_date_format() {
+        let commit = create_test_commit();
+        let date_format = DateFormat::new("%Y-%m-%d".to_string()).unwrap();
+        let dto = CommitDto::from_commit(commit, &date_format);
+
+        assert_eq!(dto.date, "2021-10-18");
     }
 
     #[test]
@@ -58,6 +183,13 @@ mod tests {
             email: "test@example.com".to_string(),
             date: "2021-10-18 12:31:30".to_string(),
             message: "Test commit message".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
         };
 
         let json = serde_json::to_string(&dto).unwrap();
@@ -0,0 +1,53 @@
+use crate::domain::entities::DirectoryStats;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct DirStatDto {
+    pub directory: String,
+    pub files: usize,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    /// Share of all changed lines, rounded to one decimal place.
+    pub percentage: f64,
+}
+
+impl DirStatDto {
+    pub fn from_directory_stats(stats: DirectoryStats, total_changed_lines: usize) -> Self {
+        let percentage = if total_changed_lines == 0 {
+            0.0
+        } else {
+            let share = stats.changed_lines() as f64 * 100.0 / total_changed_lines as f64;
+            (share * 10.0).round() / 10.0
+        };
+
+        Self {
+            directory: stats.directory().to_string(),
+            files: stats.files(),
+            lines_added: stats.lines().added,
+            lines_removed: stats.lines().removed,
+            percentage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::LineStats;
+
+    #[test]
+    fn test_dirstat_dto_from_directory_stats() {
+        let mut stats = DirectoryStats::new("src/".to_string());
+        stats.record(LineStats::new(2, 0));
+
+        let dto = DirStatDto::from_directory_stats(stats.clone(), 3);
+        assert_eq!(dto.directory, "src/");
+        assert_eq!(dto.files, 1);
+        assert_eq!(dto.lines_added, 2);
+        assert_eq!(dto.percentage, 66.7);
+
+        let dto = DirStatDto::from_directory_stats(stats, 0);
+        assert_eq!(dto.percentage, 0.0);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::application::dto::SCHEMA_VERSION;
+use crate::application::use_cases::compare_authors::CompareAuthorsError;
+use crate::application::use_cases::compare_commits::CompareCommitsError;
+use crate::application::use_cases::compare_files::CompareFilesError;
+use crate::application::use_cases::compare_issues::CompareIssuesError;
+use crate::application::use_cases::detect_affected_packages::DetectAffectedPackagesError;
+use crate::application::use_cases::generate_changelog::GenerateChangelogError;
+use crate::application::use_cases::lint_commits::LintCommitsError;
+use crate::domain::repositories::GitRepositoryError;
+use crate::domain::value_objects::BranchNameError;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ErrorDto {
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+    pub message: String,
+}
+
+/// Top-level envelope for errors reported in machine-readable output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ErrorOutputDto {
+    pub schema_version: u32,
+    pub error: ErrorDto,
+}
+
+impl ErrorDto {
+    pub fn new(kind: &str, branch: Option<String>, message: String) -> Self {
+        Self {
+            kind: kind.to_string(),
+            branch,
+            suggestions: Vec::new(),
+            message,
+        }
+    }
+}
+
+impl From<&BranchNameError> for ErrorDto {
+    fn from(error: &BranchNameError) -> Self {
+        Self::new(
+            "invalid_branch_name",
+            error.name().map(str::to_string),
+            error.to_string(),
+        )
+    }
+}
+
+impl From<ErrorDto> for ErrorOutputDto {
+    fn from(error: ErrorDto) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            error,
+        }
+    }
+}
+
+impl From<&GitRepositoryError> for ErrorDto {
+    fn from(error: &GitRepositoryError) -> Self {
+        match error {
+            GitRepositoryError::BranchNotFound {
+                branch,
+                suggestions,
+            } => Self {
+                suggestions: suggestions.clone(),
+                ..Self::new(error.kind(), Some(branch.clone()), error.to_string())
+            },
+            _ => Self::new(error.kind(), None, error.to_string()),
+        }
+    }
+}
+
+impl From<&CompareCommitsError> for ErrorDto {
+    fn from(error: &CompareCommitsError) -> Self {
+        match error {
+            CompareCommitsError::InvalidBranchName(e) => Self::from(e),
+            CompareCommitsError::RepositoryError(e) => Self::from(e),
+            CompareCommitsError::InvalidTrailer(_) => {
+                Self::new(error.kind(), None, error.to_string())
+            }
+        }
+    }
+}
+
+impl From<&CompareAuthorsError> for ErrorDto {
+    fn from(error: &CompareAuthorsError) -> Self {
+        match error {
+            CompareAuthorsError::InvalidBranchName(e) => Self::from(e),
+            CompareAuthorsError::RepositoryError(e) => Self::from(e),
+        }
+    }
+}
+
+impl From<&CompareFilesError> for ErrorDto {
+    fn from(error: &CompareFilesError) -> Self {
+        match error {
+            CompareFilesError::InvalidBranchName(e) => Self::from(e),
+            CompareFilesError::RepositoryError(e) => Self::from(e),
+            CompareFilesError::CodeOwnersNotFound | CompareFilesError::InvalidIgnoreFile(_) => {
+                Self::new(error.kind(), None, error.to_string())
+            }
+        }
+    }
+}
+
+impl From<&LintCommitsError> for ErrorDto {
+    fn from(error: &LintCommitsError) -> Self {
+        match error {
+            LintCommitsError::InvalidBranchName(e) => Self::from(e),
+            LintCommitsError::RepositoryError(e) => Self::from(e),
+        }
+    }
+}
+
+impl From<&CompareIssuesError> for ErrorDto {
+    fn from(error: &CompareIssuesError) -> Self {
+        match error {
+            CompareIssuesError::InvalidBranchName(e) => Self::from(e),
+            CompareIssuesError::RepositoryError(e) => Self::from(e),
+        }
+    }
+}
+
+impl From<&DetectAffectedPackagesError> for ErrorDto {
+    fn from(error: &DetectAffectedPackagesError) -> Self {
+        match error {
+            DetectAffectedPackagesError::InvalidBranchName(e) => Self::from(e),
+            DetectAffectedPackagesError::RepositoryError(e) => Self::from(e),
+            DetectAffectedPackagesError::InvalidWorkspace(_)
+            | DetectAffectedPackagesError::InvalidManifestPath(_)
+            | DetectAffectedPackagesError::WorkspaceNotFound => {
+                Self::new(error.kind(), None, error.to_string())
+            }
+        }
+    }
+}
+
+impl From<&GenerateChangelogError> for ErrorDto {
+    fn from(error: &GenerateChangelogError) -> Self {
+        match error {
+            GenerateChangelogError::InvalidBranchName(e) => Self::from(e),
+            GenerateChangelogError::RepositoryError(e) => Self::from(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_dto_from_branch_not_found() {
+        let error = CompareCommitsError::RepositoryError(GitRepositoryError::BranchNotFound {
+            branch: "mian".to_string(),
+            suggestions: vec!["main".to_string()],
+        });
+        let dto = ErrorDto::from(&error);
+
+        assert_eq!(dto.kind, "branch_not_found");
+        assert_eq!(dto.branch.as_deref(), Some("mian"));
+        assert_eq!(dto.suggestions, vec!["main"]);
+        assert_eq!(dto.message, "Branch not found: mian (did you mean main?)");
+    }
+
+    #[test]
+    fn test_error_dto_from_invalid_branch_name() {
+        let error =
+            CompareFilesError::InvalidBranchName(BranchNameError::DoubleDot("a..b".to_string()));
+        let dto = ErrorDto::from(&error);
+
+        assert_eq!(dto.kind, "invalid_branch_name");
+        assert_eq!(dto.branch.as_deref(), Some("a..b"));
+    }
+
+    #[test]
+    fn test_error_output_dto_serialization() {
+        let output = ErrorOutputDto::from(ErrorDto::from(&GitRepositoryError::RepositoryNotFound));
+
+        let json: serde_json::Value = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["error"]["kind"], "repository_not_found");
+        assert!(json["error"].get("branch").is_none());
+    }
+}
@@ -1,10 +1,68 @@
-use crate::domain::entities::FileChange;
+use crate::application::dto::{CommitDto, PatchDto};
+use crate::domain::entities::{FileChange, SubmoduleChange};
+use schemars::JsonSchema;
 use serde::Serialize;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct FileDto {
     pub path: String,
     pub status: String,
+    /// Code owners of the file; present only when owners were requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owners: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<PatchDto>,
+    /// Whether the file differs only in whitespace ignored with the
+    /// `--ignore-*` options.
+    pub whitespace_only: bool,
+    /// Whether `.gitattributes` marks the file `linguist-generated` or
+    /// `-diff`.
+    pub generated: bool,
+    /// Present when the path is a submodule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submodule: Option<SubmoduleDto>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SubmoduleDto {
+    /// Commit the base branch points to; `None` for an added submodule.
+    pub old_commit: Option<String>,
+    /// Commit HEAD points to; `None` for a removed submodule.
+    pub new_commit: Option<String>,
+    /// Submodule commits between the two, newest first; present only with
+    /// `--recurse-submodules` when the submodule is checked out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commits: Option<Vec<CommitDto>>,
+}
+
+/// How many changed files were left out of the results, by reason.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct HiddenFilesDto {
+    /// Files matching `exclude` patterns or `.arlonignore`.
+    pub excluded: usize,
+    /// Generated files, unless shown with `--show-generated`.
+    pub generated: usize,
+}
+
+impl HiddenFilesDto {
+    pub fn total(&self) -> usize {
+        self.excluded + self.generated
+    }
+}
+
+/// Changed files owned by one code owner, or by nobody when `owner` is
+/// `None`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OwnerGroupDto {
+    pub owner: Option<String>,
+    pub files: Vec<FileDto>,
+}
+
+impl FileDto {
+    pub fn with_owners(mut self, owners: Vec<String>) -> Self {
+        self.owners = Some(owners);
+        self
+    }
 }
 
 impl From<FileChange> for FileDto {
@@ -12,6 +70,23 @@ impl From<FileChange> for FileDto {
         Self {
             path: file_change.path().to_string(),
             status: file_change.status().as_str().to_string(),
+            owners: None,
+            patch: file_change.patch().map(PatchDto::from),
+            whitespace_only: file_change.is_whitespace_only(),
+            generated: file_change.is_generated(),
+            submodule: file_change.submodule().map(SubmoduleDto::from),
+        }
+    }
+}
+
+impl From<&SubmoduleChange> for SubmoduleDto {
+    fn from(submodule: &SubmoduleChange) -> Self {
+        Self {
+            old_commit: submodule.old_commit().map(|hash| hash.to_string()),
+            new_commit: submodule.new_commit().map(|hash| hash.to_string()),
+            commits: submodule
+                .commits()
+                .map(|commits| commits.iter().cloned().map(CommitDto::from).collect()),
         }
     }
 }
@@ -41,11 +116,21 @@ mod tests {
         let dto = FileDto {
             path: "src/main.rs".to_string(),
             status: "modified".to_string(),
+            owners: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
         };
 
         let json = serde_json::to_string(&dto).unwrap();
         assert!(json.contains("src/main.rs"));
         assert!(json.contains("modified"));
+        assert!(!json.contains("owners"));
+
+        let dto = dto.with_owners(vec!["@backend".to_string()]);
+        let json = serde_json::to_string(&dto).unwrap();
+        assert!(json.contains("\"owners\":[\"@backend\"]"));
     }
 
     #[test]
@@ -0,0 +1,11 @@
+use crate::application::dto::CommitDto;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// An issue referenced by the compared commits.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct IssueDto {
+    pub issue: String,
+    /// Commits whose messages reference the issue, newest first.
+    pub commits: Vec<CommitDto>,
+}
@@ -0,0 +1,28 @@
+use crate::application::dto::CommitDto;
+use crate::domain::value_objects::LintViolation;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// A commit whose message breaks lint rules.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LintDto {
+    #[serde(flatten)]
+    pub commit: CommitDto,
+    pub violations: Vec<LintViolationDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct LintViolationDto {
+    /// Name of the broken rule, such as `subject-length`.
+    pub rule: String,
+    pub message: String,
+}
+
+impl From<&LintViolation> for LintViolationDto {
+    fn from(violation: &LintViolation) -> Self {
+        Self {
+            rule: violation.rule().as_str().to_string(),
+            message: violation.message().to_string(),
+        }
+    }
+}
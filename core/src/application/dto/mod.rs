@@ -1,5 +1,25 @@
+pub mod author_dto;
+pub mod base_branch_dto;
+pub mod changelog_dto;
 pub mod commit_dto;
+pub mod dirstat_dto;
+pub mod error_dto;
 pub mod file_dto;
+pub mod issue_dto;
+pub mod lint_dto;
+pub mod output_dto;
+pub mod package_dto;
+pub mod patch_dto;
 
-pub use commit_dto::CommitDto;
-pub use file_dto::FileDto;
+pub use author_dto::AuthorDto;
+pub use base_branch_dto::BaseBranchDto;
+pub use changelog_dto::{ChangelogDto, ChangelogEntryDto, ChangelogOutputDto, ChangelogSectionDto};
+pub use commit_dto::{CommitDto, CommitWarningDto, TrailerDto};
+pub use dirstat_dto::DirStatDto;
+pub use error_dto::{ErrorDto, ErrorOutputDto};
+pub use file_dto::{FileDto, HiddenFilesDto, OwnerGroupDto, SubmoduleDto};
+pub use issue_dto::IssueDto;
+pub use lint_dto::{LintDto, LintViolationDto};
+pub use output_dto::{OutputDto, SCHEMA_VERSION};
+pub use package_dto::PackageDto;
+pub use patch_dto::{DiffLineDto, HunkDto, PatchDto};
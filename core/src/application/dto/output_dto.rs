@@ -0,0 +1,229 @@
+use crate::application::dto::HiddenFilesDto;
+use crate::domain::value_objects::ComparisonEndpoints;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// Version of the JSON output envelope. Bump whenever a change to
+/// `OutputDto` or any item DTO would break existing consumers.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Top-level envelope for machine-readable output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct OutputDto<T> {
+    pub schema_version: u32,
+    pub base: String,
+    pub target: String,
+    /// Commit `base` resolved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_oid: Option<String>,
+    /// Commit `target` resolved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_oid: Option<String>,
+    /// Best common ancestor of the base and target, absent for unrelated
+    /// histories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_base: Option<String>,
+    pub items: Vec<T>,
+    /// Changed files left out of `items`; reported by the files command.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<HiddenFilesDto>,
+}
+
+impl<T> OutputDto<T> {
+    pub fn new(base: String, target: String, items: Vec<T>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            base,
+            target,
+            base_oid: None,
+            target_oid: None,
+            merge_base: None,
+            items,
+            hidden: None,
+        }
+    }
+
+    /// Records the commits the comparison was made between.
+    pub fn with_endpoints(mut self, endpoints: &ComparisonEndpoints) -> Self {
+        self.base_oid = Some(endpoints.base.to_string());
+        self.target_oid = Some(endpoints.target.to_string());
+        self.merge_base = endpoints.merge_base.as_ref().map(ToString::to_string);
+        self
+    }
+
+    pub fn with_hidden(mut self, hidden: HiddenFilesDto) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{
+        AuthorDto, ChangelogOutputDto, CommitDto, DirStatDto, ErrorOutputDto, FileDto, IssueDto,
+        LintDto, OwnerGroupDto, PackageDto,
+    };
+    use crate::domain::value_objects::CommitHash;
+    use std::path::PathBuf;
+
+    fn schema_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("schema")
+            .join(format!("{}.schema.json", name))
+    }
+
+    fn assert_schema_matches<T: JsonSchema>(name: &str) {
+        let generated = serde_json::to_string_pretty(&schemars::schema_for!(T)).unwrap() + "\n";
+        let path = schema_path(name);
+
+        if std::env::var_os("ARLON_UPDATE_SCHEMA").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &generated).unwrap();
+            return;
+        }
+
+        let published = std::fs::read_to_string(&path).unwrap_or_default();
+        assert_eq!(
+            published,
+            generated,
+            "{} is out of date; rerun with ARLON_UPDATE_SCHEMA=1 and bump SCHEMA_VERSION if needed",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn test_output_dto_new() {
+        let output = OutputDto::new("main".to_string(), "HEAD".to_string(), vec![1, 2]);
+
+        assert_eq!(output.schema_version, SCHEMA_VERSION);
+        assert_eq!(output.base, "main");
+        assert_eq!(output.target, "HEAD");
+        assert_eq!(output.items, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_output_dto_serialization() {
+        let output = OutputDto::new(
+            "main".to_string(),
+            "HEAD".to_string(),
+            vec![FileDto {
+                path: "src/main.rs".to_string(),
+                status: "modified".to_string(),
+                owners: None,
+                patch: None,
+                whitespace_only: false,
+                generated: false,
+                submodule: None,
+            }],
+        );
+
+        let json: serde_json::Value = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(json["base"], "main");
+        assert_eq!(json["target"], "HEAD");
+        assert!(json.get("base_oid").is_none());
+        assert_eq!(json["items"][0]["path"], "src/main.rs");
+    }
+
+    #[test]
+    fn test_output_dto_with_endpoints() {
+        let hash = |c: char| CommitHash::new(c.to_string().repeat(40)).unwrap();
+        let output = OutputDto::new("main".to_string(), "HEAD".to_string(), vec![1])
+            .with_endpoints(&ComparisonEndpoints {
+                base: hash('a'),
+                target: hash('b'),
+                merge_base: Some(hash('c')),
+            });
+
+        let json: serde_json::Value = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["base_oid"], "a".repeat(40));
+        assert_eq!(json["target_oid"], "b".repeat(40));
+        assert_eq!(json["merge_base"], "c".repeat(40));
+
+        let output = output.with_endpoints(&ComparisonEndpoints {
+            base: hash('a'),
+            target: hash('b'),
+            merge_base: None,
+        });
+        let json: serde_json::Value = serde_json::to_value(&output).unwrap();
+        assert!(json.get("merge_base").is_none());
+    }
+
+    #[test]
+    fn test_commits_schema_is_published() {
+        assert_schema_matches::<OutputDto<CommitDto>>("commits");
+    }
+
+    #[test]
+    fn test_files_schema_is_published() {
+        assert_schema_matches::<OutputDto<FileDto>>("files");
+    }
+
+    #[test]
+    fn test_dirstat_schema_is_published() {
+        assert_schema_matches::<OutputDto<DirStatDto>>("dirstat");
+    }
+
+    #[test]
+    fn test_authors_schema_is_published() {
+        assert_schema_matches::<OutputDto<AuthorDto>>("authors");
+    }
+
+    #[test]
+    fn test_owners_schema_is_published() {
+        assert_schema_matches::<OutputDto<OwnerGroupDto>>("owners");
+    }
+
+    #[test]
+    fn test_packages_schema_is_published() {
+        assert_schema_matches::<OutputDto<PackageDto>>("packages");
+    }
+
+    #[test]
+    fn test_changelog_schema_is_published() {
+        assert_schema_matches::<ChangelogOutputDto>("changelog");
+    }
+
+    #[test]
+    fn test_issues_schema_is_published() {
+        assert_schema_matches::<OutputDto<IssueDto>>("issues");
+    }
+
+    #[test]
+    fn test_lint_schema_is_published() {
+        assert_schema_matches::<OutputDto<LintDto>>("lint");
+    }
+
+    #[test]
+    fn test_published_schemas_include_endpoints() {
+        let envelopes = [
+            "commits",
+            "files",
+            "dirstat",
+            "authors",
+            "owners",
+            "packages",
+            "changelog",
+            "issues",
+            "lint",
+        ];
+        for name in envelopes {
+            let published = std::fs::read_to_string(schema_path(name)).unwrap();
+            let schema: serde_json::Value = serde_json::from_str(&published).unwrap();
+            for field in ["base_oid", "target_oid", "merge_base"] {
+                assert!(
+                    schema["properties"].get(field).is_some(),
+                    "{} schema is missing {}",
+                    name,
+                    field
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_schema_is_published() {
+        assert_schema_matches::<ErrorOutputDto>("error");
+    }
+}
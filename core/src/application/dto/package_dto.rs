@@ -0,0 +1,15 @@
+use schemars::JsonSchema;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct PackageDto {
+    pub name: String,
+    /// Package directory relative to the repository root (`.` for the root);
+    /// absent for packages defined in the `packages` config table.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Where the package was found: `cargo`, `npm` or `config`.
+    pub source: String,
+    /// Number of changed files in the package.
+    pub files: usize,
+}
@@ -0,0 +1,73 @@
+use crate::domain::entities::{DiffLine, FilePatch, Hunk};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// The diff of one file, present only with `--patch`.
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct PatchDto {
+    /// Path before a rename or copy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_path: Option<String>,
+    /// Octal file mode before the change, absent for an added file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_mode: Option<String>,
+    /// Octal file mode after the change, absent for a deleted file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_mode: Option<String>,
+    /// Binary files have no hunks.
+    pub binary: bool,
+    pub hunks: Vec<HunkDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct HunkDto {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    /// Text after the `@@` range, usually the enclosing function.
+    pub header: String,
+    pub lines: Vec<DiffLineDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct DiffLineDto {
+    /// `context`, `added` or `removed`.
+    pub kind: String,
+    /// The line without its trailing newline.
+    pub content: String,
+}
+
+impl From<&FilePatch> for PatchDto {
+    fn from(patch: &FilePatch) -> Self {
+        Self {
+            old_path: patch.old_path().map(|path| path.to_string()),
+            old_mode: patch.old_mode().map(|mode| format!("{:o}", mode)),
+            new_mode: patch.new_mode().map(|mode| format!("{:o}", mode)),
+            binary: patch.is_binary(),
+            hunks: patch.hunks().iter().map(HunkDto::from).collect(),
+        }
+    }
+}
+
+impl From<&Hunk> for HunkDto {
+    fn from(hunk: &Hunk) -> Self {
+        Self {
+            old_start: hunk.old_start,
+            old_lines: hunk.old_lines,
+            new_start: hunk.new_start,
+            new_lines: hunk.new_lines,
+            header: hunk.header.clone(),
+            lines: hunk.lines.iter().map(DiffLineDto::from).collect(),
+        }
+    }
+}
+
+impl From<&DiffLine> for DiffLineDto {
+    fn from(line: &DiffLine) -> Self {
+        Self {
+            kind: line.kind.as_str().to_string(),
+            content: line.content.clone(),
+        }
+    }
+}
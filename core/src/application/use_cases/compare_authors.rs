@@ -0,0 +1,188 @@
+use crate::application::dto::AuthorDto;
+use crate::application::use_cases::CompareCommitsUseCase;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::services::AuthorStatisticsDomainService;
+use crate::domain::value_objects::{BranchName, BranchNameError, DateFormat};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareAuthorsError {
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+}
+
+impl CompareAuthorsError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+pub struct CompareAuthorsUseCase<'a, R> {
+    git_repository: &'a R,
+    date_format: DateFormat,
+    line_stats: bool,
+}
+
+impl<'a, R: GitRepository> CompareAuthorsUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self {
+            git_repository,
+            date_format: DateFormat::default(),
+            line_stats: false,
+        }
+    }
+
+    pub fn with_date_format(mut self, date_format: DateFormat) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Also count lines added and removed per author.
+    pub fn with_line_stats(mut self, line_stats: bool) -> Self {
+        self.line_stats = line_stats;
+        self
+    }
+
+    pub fn execute(&self, branch_name: String) -> Result<Vec<AuthorDto>, CompareAuthorsError> {
+        let branch = BranchName::new(branch_name)?;
+        let commits =
+            CompareCommitsUseCase::new(self.git_repository).commits_not_in_branch(&branch)?;
+
+        let commits = commits
+            .into_iter()
+            .map(|commit| {
+                let lines = if self.line_stats {
+                    Some(self.git_repository.get_commit_line_stats(commit.hash())?)
+                } else {
+                    None
+                };
+                Ok((commit, lines))
+            })
+            .collect::<Result<Vec<_>, GitRepositoryError>>()?;
+
+        Ok(AuthorStatisticsDomainService::aggregate(commits)
+            .into_iter()
+            .map(|stats| AuthorDto::from_author_stats(stats, &self.date_format))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{BaseBranchCandidates, Commit, FileChange};
+    use crate::domain::value_objects::{CommitHash, FileComparisonOptions, LineStats};
+    use mockall::mock;
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &FileComparisonOptions,
+            ) -> Result<Vec<FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(&self, commit: &CommitHash) -> Result<LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(&self) -> Result<BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    fn create_test_commit(hash: char, author: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            author.to_string(),
+            format!("{}@example.com", author.to_lowercase()),
+            1634567890,
+            "Test commit message".to_string(),
+        )
+    }
+
+    fn mock_with_head(commits: Vec<Commit>) -> MockTestGitRepository {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .times(1)
+            .returning(move |_, _| Ok(commits.clone()));
+        mock_repo
+    }
+
+    #[test]
+    fn test_execute_aggregates_authors() {
+        let mut mock_repo = mock_with_head(vec![
+            create_test_commit('a', "Alice"),
+            create_test_commit('b', "Bob"),
+            create_test_commit('c', "Alice"),
+        ]);
+        mock_repo.expect_get_commit_line_stats().never();
+
+        let use_case = CompareAuthorsUseCase::new(&mock_repo);
+        let authors = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[0].name, "Alice");
+        assert_eq!(authors[0].commits, 2);
+        assert_eq!(authors[0].lines_added, None);
+        assert_eq!(authors[1].name, "Bob");
+    }
+
+    #[test]
+    fn test_execute_with_line_stats() {
+        let mut mock_repo = mock_with_head(vec![
+            create_test_commit('a', "Alice"),
+            create_test_commit('b', "Alice"),
+        ]);
+        mock_repo
+            .expect_get_commit_line_stats()
+            .times(2)
+            .returning(|_| Ok(LineStats::new(4, 1)));
+
+        let use_case = CompareAuthorsUseCase::new(&mock_repo).with_line_stats(true);
+        let authors = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(authors[0].lines_added, Some(8));
+        assert_eq!(authors[0].lines_removed, Some(2));
+    }
+
+    #[test]
+    fn test_execute_invalid_branch_name() {
+        let mock_repo = MockTestGitRepository::new();
+        let use_case = CompareAuthorsUseCase::new(&mock_repo);
+
+        let result = use_case.execute("".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CompareAuthorsError::InvalidBranchName(_)
+        ));
+    }
+}
@@ -1,7 +1,11 @@
-use crate::application::dto::CommitDto;
+use crate::application::dto::{CommitDto, FileDto};
+use crate::domain::entities::Commit;
 use crate::domain::repositories::{GitRepository, GitRepositoryError};
-use crate::domain::services::CommitComparisonDomainService;
-use crate::domain::value_objects::{BranchName, BranchNameError};
+use crate::domain::services::{BranchHygieneDomainService, IssueReferenceDomainService};
+use crate::domain::value_objects::{
+    BranchName, BranchNameError, DateFormat, FileComparisonOptions, IssuePatterns,
+    RequiredTrailers, RequiredTrailersError, SignatureStatus, SignatureVerification,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CompareCommitsError {
@@ -9,36 +13,158 @@ pub enum CompareCommitsError {
     InvalidBranchName(#[from] BranchNameError),
     #[error("Git repository error: {0}")]
     RepositoryError(#[from] GitRepositoryError),
+    #[error(transparent)]
+    InvalidTrailer(#[from] RequiredTrailersError),
+}
+
+impl CompareCommitsError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::RepositoryError(e) => e.kind(),
+            Self::InvalidTrailer(_) => "invalid_trailer",
+        }
+    }
 }
 
 pub struct CompareCommitsUseCase<'a, R> {
     git_repository: &'a R,
+    date_format: DateFormat,
+    issue_patterns: IssuePatterns,
+    required_trailers: Vec<String>,
+    signature_verification: Option<SignatureVerification>,
+    require_signed: bool,
+    file_options: Option<FileComparisonOptions>,
 }
 
 impl<'a, R: GitRepository> CompareCommitsUseCase<'a, R> {
     pub fn new(git_repository: &'a R) -> Self {
-        Self { git_repository }
+        Self {
+            git_repository,
+            date_format: DateFormat::default(),
+            issue_patterns: IssuePatterns::default(),
+            required_trailers: Vec::new(),
+            signature_verification: None,
+            require_signed: false,
+            file_options: None,
+        }
+    }
+
+    pub fn with_date_format(mut self, date_format: DateFormat) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    pub fn with_issue_patterns(mut self, issue_patterns: IssuePatterns) -> Self {
+        self.issue_patterns = issue_patterns;
+        self
+    }
+
+    /// Only report commits that lack one of these trailers, e.g.
+    /// `Signed-off-by`.
+    pub fn with_required_trailers(mut self, required_trailers: Vec<String>) -> Self {
+        self.required_trailers = required_trailers;
+        self
+    }
+
+    /// Verify commit signatures against these keys instead of only
+    /// reporting whether commits are signed.
+    pub fn with_signature_verification(
+        mut self,
+        signature_verification: Option<SignatureVerification>,
+    ) -> Self {
+        self.signature_verification = signature_verification;
+        self
+    }
+
+    /// Only report commits that are unsigned or, when verifying, whose
+    /// signature is not good.
+    pub fn with_require_signed(mut self, require_signed: bool) -> Self {
+        self.require_signed = require_signed;
+        self
+    }
+
+    /// List the files each commit changed, detecting renames as set in
+    /// `file_options`.
+    pub fn with_name_status(mut self, file_options: Option<FileComparisonOptions>) -> Self {
+        self.file_options = file_options;
+        self
     }
 
     pub fn execute(&self, branch_name: String) -> Result<Vec<CommitDto>, CompareCommitsError> {
         let branch = BranchName::new(branch_name)?;
+        let required_trailers = RequiredTrailers::new(self.required_trailers.clone())?;
+        let commits = self.commits_not_in_branch(&branch)?;
+        let warnings: Vec<_> = commits
+            .iter()
+            .map(|commit| BranchHygieneDomainService::warnings(commit, &commits))
+            .collect();
+
+        let checking = !required_trailers.is_empty() || self.require_signed;
+
+        let mut dtos = Vec::new();
+        for (commit, warnings) in commits.into_iter().zip(warnings) {
+            let missing_trailers = required_trailers.missing(&commit.trailers());
+            let signature = self.signature_status(&commit)?;
+            let unsigned = self.require_signed
+                && match self.signature_verification {
+                    Some(_) => signature != SignatureStatus::Good,
+                    None => signature == SignatureStatus::Unsigned,
+                };
+            if checking && missing_trailers.is_empty() && !unsigned {
+                continue;
+            }
+
+            let issues = IssueReferenceDomainService::references(&commit, &self.issue_patterns);
+            let files = match &self.file_options {
+                Some(options) => Some(
+                    self.git_repository
+                        .get_commit_file_changes(commit.hash(), options)?
+                        .into_iter()
+                        .map(FileDto::from)
+                        .collect(),
+                ),
+                None => None,
+            };
+            let dto = CommitDto::from_commit(commit, &self.date_format)
+                .with_issues(issues)
+                .with_warnings(&warnings)
+                .with_missing_trailers(missing_trailers)
+                .with_signature_status(signature);
+            dtos.push(match files {
+                Some(files) => dto.with_files(files),
+                None => dto,
+            });
+        }
 
-        let head_commits = self.git_repository.get_commits_from_head()?;
-        let branch_commits = self.git_repository.get_commits_from_branch(&branch)?;
+        Ok(dtos)
+    }
 
-        let commits =
-            CommitComparisonDomainService::commits_not_in_branch(head_commits, branch_commits);
+    fn signature_status(&self, commit: &Commit) -> Result<SignatureStatus, GitRepositoryError> {
+        match (&self.signature_verification, commit.signature()) {
+            (_, None) => Ok(SignatureStatus::Unsigned),
+            (None, Some(_)) => Ok(SignatureStatus::Signed),
+            (Some(verification), Some(_)) => self
+                .git_repository
+                .verify_commit_signature(commit.hash(), verification),
+        }
+    }
 
-        Ok(commits.into_iter().map(CommitDto::from).collect())
+    /// Commits reachable from HEAD but not from `branch`, newest first.
+    pub fn commits_not_in_branch(
+        &self,
+        branch: &BranchName,
+    ) -> Result<Vec<Commit>, GitRepositoryError> {
+        self.git_repository.get_commits_not_in_branch(branch, None)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::Commit;
+    use crate::domain::entities::{FileChange, FileChangeStatus};
     use crate::domain::repositories::{GitRepository, GitRepositoryError};
-    use crate::domain::value_objects::{BranchName, CommitHash};
+    use crate::domain::value_objects::{BranchName, CommitHash, FilePath, SignatureFormat};
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -46,13 +172,43 @@ mod tests {
         TestGitRepository {}
 
         impl GitRepository for TestGitRepository {
-            fn get_commits_from_head(&self) -> Result<Vec<Commit>, GitRepositoryError>;
-            fn get_commits_from_branch(&self, branch: &BranchName) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
 
             fn get_file_changes_between_branches(
                 &self,
                 branch: &BranchName,
+                options: &crate::domain::value_objects::FileComparisonOptions,
             ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
         }
     }
 
@@ -71,18 +227,16 @@ mod tests {
     fn test_execute_success() {
         let mut mock_repo = MockTestGitRepository::new();
         let test_commit = create_test_commit();
-        let head_commits = vec![test_commit.clone()];
-        let branch_commits = vec![];
-
-        mock_repo
-            .expect_get_commits_from_head()
-            .times(1)
-            .returning(move || Ok(head_commits.clone()));
+        let commits = vec![test_commit.clone()];
 
         mock_repo
-            .expect_get_commits_from_branch()
+            .expect_get_commits_not_in_branch()
+            .with(
+                function(|branch: &BranchName| branch.as_str() == "main"),
+                function(|target: &Option<BranchName>| target.is_none()),
+            )
             .times(1)
-            .returning(move |_| Ok(branch_commits.clone()));
+            .returning(move |_, _| Ok(commits.clone()));
 
         let use_case = CompareCommitsUseCase::new(&mock_repo);
         let result = use_case.execute("main".to_string());
@@ -113,11 +267,14 @@ mod tests {
     fn test_execute_repository_error() {
         let mut mock_repo = MockTestGitRepository::new();
 
-        mock_repo.expect_get_commits_from_head().returning(|| {
-            Err(GitRepositoryError::BranchNotFound {
-                branch: "nonexistent".to_string(),
-            })
-        });
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(|_, _| {
+                Err(GitRepositoryError::BranchNotFound {
+                    branch: "nonexistent".to_string(),
+                    suggestions: vec![],
+                })
+            });
 
         let use_case = CompareCommitsUseCase::new(&mock_repo);
         let result = use_case.execute("nonexistent".to_string());
@@ -134,12 +291,8 @@ mod tests {
         let mut mock_repo = MockTestGitRepository::new();
 
         mock_repo
-            .expect_get_commits_from_head()
-            .returning(|| Ok(vec![]));
-
-        mock_repo
-            .expect_get_commits_from_branch()
-            .returning(|_| Ok(vec![]));
+            .expect_get_commits_not_in_branch()
+            .returning(|_, _| Ok(vec![]));
 
         let use_case = CompareCommitsUseCase::new(&mock_repo);
         let result = use_case.execute("main".to_string());
@@ -148,4 +301,226 @@ mod tests {
         let commits = result.unwrap();
         assert_eq!(commits.len(), 0);
     }
+
+    #[test]
+    fn test_execute_with_date_format() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let head_commits = vec![create_test_commit()];
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(head_commits.clone()));
+
+        let date_format = DateFormat::new("%Y/%m/%d".to_string()).unwrap();
+        let use_case = CompareCommitsUseCase::new(&mock_repo).with_date_format(date_format);
+        let commits = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(commits[0].date, "2021/10/18");
+    }
+
+    #[test]
+    fn test_execute_with_issue_patterns() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let commit = Commit::new(
+            CommitHash::new("abcdef1234567890abcdef1234567890abcdef12".to_string()).unwrap(),
+            "Test Author".to_string(),
+            "test@example.com".to_string(),
+            1634567890,
+            "Fix login for #12 (task 981)".to_string(),
+        );
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(vec![commit.clone()]));
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .execute("main".to_string())
+            .unwrap();
+        assert_eq!(commits[0].issues, vec!["#12"]);
+
+        let patterns = IssuePatterns::new(vec![r"task (\d+)".to_string()]).unwrap();
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .with_issue_patterns(patterns)
+            .execute("main".to_string())
+            .unwrap();
+        assert_eq!(commits[0].issues, vec!["981"]);
+    }
+
+    #[test]
+    fn test_execute_with_required_trailers() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let signed = Commit::new(
+            CommitHash::new("1".repeat(40)).unwrap(),
+            "Test Author".to_string(),
+            "test@example.com".to_string(),
+            1634567890,
+            "Signed commit".to_string(),
+        )
+        .with_body("Signed-off-by: Test Author <test@example.com>".to_string());
+        let unsigned = create_test_commit();
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(vec![signed.clone(), unsigned.clone()]));
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .with_required_trailers(vec!["signed-off-by".to_string()])
+            .execute("main".to_string())
+            .unwrap();
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message, "Test commit message");
+        assert_eq!(commits[0].missing_trailers, vec!["signed-off-by"]);
+    }
+
+    #[test]
+    fn test_execute_invalid_required_trailer() {
+        let mock_repo = MockTestGitRepository::new();
+
+        let result = CompareCommitsUseCase::new(&mock_repo)
+            .with_required_trailers(vec!["Signed off by".to_string()])
+            .execute("main".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CompareCommitsError::InvalidTrailer(_)
+        ));
+    }
+
+    #[test]
+    fn test_execute_with_warnings() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let hash = |c: char| CommitHash::new(c.to_string().repeat(40)).unwrap();
+        let commit = |c: char, summary: &str, parents: Vec<CommitHash>| {
+            Commit::new(
+                hash(c),
+                "Test Author".to_string(),
+                "test@example.com".to_string(),
+                1634567890,
+                summary.to_string(),
+            )
+            .with_parents(parents)
+        };
+        let head_commits = vec![
+            commit('c', "Merge branch 'main'", vec![hash('b'), hash('1')]),
+            commit('b', "fixup! Add parser", vec![hash('a')]),
+            commit('a', "Add parser", vec![hash('1')]),
+        ];
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(head_commits.clone()));
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .execute("main".to_string())
+            .unwrap();
+
+        let kinds: Vec<Vec<&str>> = commits
+            .iter()
+            .map(|commit| {
+                commit
+                    .warnings
+                    .iter()
+                    .map(|warning| warning.kind.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![vec!["merge_from_base"], vec!["unsquashed_fixup"], vec![]]
+        );
+        assert_eq!(commits[1].warnings[0].commit, "a".repeat(40));
+    }
+
+    #[test]
+    fn test_execute_with_require_signed() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let commit = |c: char| {
+            Commit::new(
+                CommitHash::new(c.to_string().repeat(40)).unwrap(),
+                "Test Author".to_string(),
+                "test@example.com".to_string(),
+                1634567890,
+                format!("Commit {}", c),
+            )
+        };
+        let head_commits = vec![
+            commit('a').with_signature(SignatureFormat::Ssh),
+            commit('b').with_signature(SignatureFormat::Ssh),
+            commit('c'),
+        ];
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(head_commits.clone()));
+        mock_repo
+            .expect_verify_commit_signature()
+            .returning(|hash, _| {
+                Ok(if hash.as_str().starts_with('a') {
+                    SignatureStatus::Good
+                } else {
+                    SignatureStatus::UnknownKey
+                })
+            });
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .with_require_signed(true)
+            .execute("main".to_string())
+            .unwrap();
+        let signatures: Vec<_> = commits
+            .iter()
+            .map(|commit| (commit.message.as_str(), commit.signature.as_str()))
+            .collect();
+        assert_eq!(signatures, vec![("Commit c", "unsigned")]);
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .with_signature_verification(Some(SignatureVerification::default()))
+            .with_require_signed(true)
+            .execute("main".to_string())
+            .unwrap();
+        let signatures: Vec<_> = commits
+            .iter()
+            .map(|commit| (commit.message.as_str(), commit.signature.as_str()))
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![("Commit b", "unknown_key"), ("Commit c", "unsigned")]
+        );
+    }
+
+    #[test]
+    fn test_execute_with_name_status() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let head_commits = vec![create_test_commit()];
+
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(move |_, _| Ok(head_commits.clone()));
+        mock_repo
+            .expect_get_commit_file_changes()
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![FileChange::new(
+                    FilePath::new("src/lib.rs".to_string()).unwrap(),
+                    FileChangeStatus::Modified,
+                )])
+            });
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .execute("main".to_string())
+            .unwrap();
+        assert!(commits[0].files.is_none());
+
+        let commits = CompareCommitsUseCase::new(&mock_repo)
+            .with_name_status(Some(FileComparisonOptions::default()))
+            .execute("main".to_string())
+            .unwrap();
+        let files: Vec<_> = commits[0]
+            .files
+            .iter()
+            .flatten()
+            .map(|file| (file.status.as_str(), file.path.as_str()))
+            .collect();
+        assert_eq!(files, vec![("modified", "src/lib.rs")]);
+    }
 }
@@ -1,6 +1,12 @@
-use crate::application::dto::FileDto;
+use crate::application::dto::{DirStatDto, FileDto, HiddenFilesDto, OwnerGroupDto};
+use crate::domain::entities::FileChange;
 use crate::domain::repositories::{GitRepository, GitRepositoryError};
-use crate::domain::value_objects::{BranchName, BranchNameError};
+use crate::domain::services::DirectoryStatisticsDomainService;
+use crate::domain::value_objects::{
+    BranchName, BranchNameError, CodeOwners, ExcludePatterns, ExcludePatternsError,
+    FileComparisonOptions, FilePath,
+};
+use std::collections::BTreeMap;
 
 #[derive(Debug, thiserror::Error)]
 pub enum CompareFilesError {
@@ -8,46 +14,255 @@ pub enum CompareFilesError {
     InvalidBranchName(#[from] BranchNameError),
     #[error("Git repository error: {0}")]
     RepositoryError(#[from] GitRepositoryError),
+    #[error("No CODEOWNERS file found in HEAD (looked in {})", CodeOwners::LOCATIONS.join(", "))]
+    CodeOwnersNotFound,
+    #[error("Invalid {}: {0}", ExcludePatterns::IGNORE_FILE)]
+    InvalidIgnoreFile(#[source] ExcludePatternsError),
+}
+
+impl CompareFilesError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::CodeOwnersNotFound => "codeowners_not_found",
+            Self::InvalidIgnoreFile(_) => "invalid_config",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
 }
 
 pub struct CompareFilesUseCase<'a, R> {
     git_repository: &'a R,
+    options: FileComparisonOptions,
+    excludes: ExcludePatterns,
+    owners: bool,
+    show_generated: bool,
 }
 
 impl<'a, R: GitRepository> CompareFilesUseCase<'a, R> {
     pub fn new(git_repository: &'a R) -> Self {
-        Self { git_repository }
+        Self {
+            git_repository,
+            options: FileComparisonOptions::default(),
+            excludes: ExcludePatterns::default(),
+            owners: false,
+            show_generated: false,
+        }
+    }
+
+    pub fn with_options(mut self, options: FileComparisonOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_excludes(mut self, excludes: ExcludePatterns) -> Self {
+        self.excludes = excludes;
+        self
+    }
+
+    /// Annotate each file with its owners from HEAD's `CODEOWNERS`.
+    pub fn with_owners(mut self, owners: bool) -> Self {
+        self.owners = owners;
+        self
+    }
+
+    /// Keep files that `.gitattributes` marks as generated instead of
+    /// hiding them.
+    pub fn with_show_generated(mut self, show_generated: bool) -> Self {
+        self.show_generated = show_generated;
+        self
+    }
+
+    /// Changed files, with how many were hidden as excluded or generated.
+    pub fn execute(
+        &self,
+        branch_name: String,
+    ) -> Result<(Vec<FileDto>, HiddenFilesDto), CompareFilesError> {
+        let (file_changes, hidden) = self.file_changes(branch_name, &self.options)?;
+
+        if !self.owners {
+            return Ok((
+                file_changes.into_iter().map(FileDto::from).collect(),
+                hidden,
+            ));
+        }
+
+        let code_owners = self.code_owners()?;
+        let files = file_changes
+            .into_iter()
+            .map(|file_change| {
+                let owners = code_owners.owners_of(file_change.path());
+                FileDto::from(file_change).with_owners(owners)
+            })
+            .collect();
+        Ok((files, hidden))
+    }
+
+    /// Changed files grouped by code owner, sorted by owner with unowned
+    /// files last. A file with several owners appears in each group.
+    pub fn execute_by_owner(
+        &self,
+        branch_name: String,
+    ) -> Result<(Vec<OwnerGroupDto>, HiddenFilesDto), CompareFilesError> {
+        let (file_changes, hidden) = self.file_changes(branch_name, &self.options)?;
+        let code_owners = self.code_owners()?;
+
+        let mut owned: BTreeMap<String, Vec<FileDto>> = BTreeMap::new();
+        let mut unowned = Vec::new();
+        for file_change in file_changes {
+            let owners = code_owners.owners_of(file_change.path());
+            let file = FileDto::from(file_change);
+            if owners.is_empty() {
+                unowned.push(file);
+                continue;
+            }
+            for owner in owners {
+                owned.entry(owner).or_default().push(file.clone());
+            }
+        }
+
+        let mut groups: Vec<OwnerGroupDto> = owned
+            .into_iter()
+            .map(|(owner, files)| OwnerGroupDto {
+                owner: Some(owner),
+                files,
+            })
+            .collect();
+        if !unowned.is_empty() {
+            groups.push(OwnerGroupDto {
+                owner: None,
+                files: unowned,
+            });
+        }
+        Ok((groups, hidden))
+    }
+
+    /// Changed files grouped by their first `depth` directory components,
+    /// with each directory's share of the changed lines.
+    pub fn execute_dirstat(
+        &self,
+        branch_name: String,
+        depth: usize,
+    ) -> Result<(Vec<DirStatDto>, HiddenFilesDto), CompareFilesError> {
+        let options = FileComparisonOptions {
+            line_stats: true,
+            ..self.options.clone()
+        };
+        let (file_changes, hidden) = self.file_changes(branch_name, &options)?;
+
+        let directories = DirectoryStatisticsDomainService::aggregate(&file_changes, depth);
+        let total_changed_lines = directories.iter().map(|stats| stats.changed_lines()).sum();
+
+        let directories = directories
+            .into_iter()
+            .map(|stats| DirStatDto::from_directory_stats(stats, total_changed_lines))
+            .collect();
+        Ok((directories, hidden))
+    }
+
+    fn code_owners(&self) -> Result<CodeOwners, CompareFilesError> {
+        for location in CodeOwners::LOCATIONS {
+            let path =
+                FilePath::new(location.to_string()).expect("CODEOWNERS locations are not empty");
+            if let Some(contents) = self.git_repository.get_file_at_head(&path)? {
+                return Ok(CodeOwners::parse(&contents));
+            }
+        }
+
+        Err(CompareFilesError::CodeOwnersNotFound)
     }
 
-    pub fn execute(&self, branch_name: String) -> Result<Vec<FileDto>, CompareFilesError> {
+    /// The configured excludes plus the patterns in HEAD's `.arlonignore`.
+    fn excludes(&self) -> Result<ExcludePatterns, CompareFilesError> {
+        let path = FilePath::new(ExcludePatterns::IGNORE_FILE.to_string())
+            .expect("the ignore file name is not empty");
+        match self.git_repository.get_file_at_head(&path)? {
+            Some(contents) => self
+                .excludes
+                .clone()
+                .with_ignore_file(&contents)
+                .map_err(CompareFilesError::InvalidIgnoreFile),
+            None => Ok(self.excludes.clone()),
+        }
+    }
+
+    fn file_changes(
+        &self,
+        branch_name: String,
+        options: &FileComparisonOptions,
+    ) -> Result<(Vec<FileChange>, HiddenFilesDto), CompareFilesError> {
         let branch = BranchName::new(branch_name)?;
         let file_changes = self
             .git_repository
-            .get_file_changes_between_branches(&branch)?;
+            .get_file_changes_between_branches(&branch, options)?;
+        let excludes = self.excludes()?;
 
-        Ok(file_changes.into_iter().map(FileDto::from).collect())
+        let mut hidden = HiddenFilesDto::default();
+        let mut kept = Vec::with_capacity(file_changes.len());
+        for file_change in file_changes {
+            if excludes.is_excluded(file_change.path()) {
+                hidden.excluded += 1;
+            } else if file_change.is_generated() && !self.show_generated {
+                hidden.generated += 1;
+            } else {
+                kept.push(file_change);
+            }
+        }
+        Ok((kept, hidden))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::{FileChange, FileChangeStatus};
+    use crate::domain::entities::FileChangeStatus;
     use crate::domain::repositories::{GitRepository, GitRepositoryError};
-    use crate::domain::value_objects::{BranchName, FilePath};
+    use crate::domain::value_objects::{
+        BranchName, FilePath, LineStats, RenameDetection, WhitespaceOptions,
+    };
     use mockall::mock;
 
     mock! {
         TestGitRepository {}
 
         impl GitRepository for TestGitRepository {
-            fn get_commits_from_head(&self) -> Result<Vec<crate::domain::entities::Commit>, GitRepositoryError>;
-            fn get_commits_from_branch(&self, branch: &BranchName) -> Result<Vec<crate::domain::entities::Commit>, GitRepositoryError>;
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<crate::domain::entities::Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
 
             fn get_file_changes_between_branches(
                 &self,
                 branch: &BranchName,
+                options: &FileComparisonOptions,
             ) -> Result<Vec<FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
         }
     }
 
@@ -65,13 +280,15 @@ mod tests {
         mock_repo
             .expect_get_file_changes_between_branches()
             .times(1)
-            .returning(move |_| Ok(expected_changes.clone()));
+            .returning(move |_, _| Ok(expected_changes.clone()));
+        mock_repo.expect_get_file_at_head().returning(|_| Ok(None));
 
         let use_case = CompareFilesUseCase::new(&mock_repo);
         let result = use_case.execute("main".to_string());
 
         assert!(result.is_ok());
-        let files = result.unwrap();
+        let (files, hidden) = result.unwrap();
+        assert_eq!(hidden, HiddenFilesDto::default());
         assert_eq!(files.len(), 1);
         assert_eq!(files[0].path, "src/main.rs");
         assert_eq!(files[0].status, "modified");
@@ -97,9 +314,10 @@ mod tests {
 
         mock_repo
             .expect_get_file_changes_between_branches()
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(GitRepositoryError::BranchNotFound {
                     branch: "nonexistent".to_string(),
+                    suggestions: vec![],
                 })
             });
 
@@ -119,13 +337,245 @@ mod tests {
 
         mock_repo
             .expect_get_file_changes_between_branches()
-            .returning(|_| Ok(vec![]));
+            .returning(|_, _| Ok(vec![]));
+        mock_repo.expect_get_file_at_head().returning(|_| Ok(None));
 
         let use_case = CompareFilesUseCase::new(&mock_repo);
         let result = use_case.execute("main".to_string());
 
         assert!(result.is_ok());
-        let files = result.unwrap();
+        let (files, _) = result.unwrap();
         assert_eq!(files.len(), 0);
     }
+
+    #[test]
+    fn test_execute_passes_options() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let options = FileComparisonOptions {
+            renames: RenameDetection::Enabled { threshold: 70 },
+            rename_limit: Some(400),
+            line_stats: false,
+            patch_context: None,
+            whitespace: WhitespaceOptions {
+                ignore_all_space: true,
+                ..WhitespaceOptions::default()
+            },
+            recurse_submodules: true,
+        };
+        let expected_options = options.clone();
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .withf(move |_, options| *options == expected_options)
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        mock_repo.expect_get_file_at_head().returning(|_| Ok(None));
+
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_options(options);
+        assert!(use_case.execute("main".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_execute_applies_excludes() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let changes = vec![
+            create_test_file_change(),
+            FileChange::new(
+                FilePath::new("Cargo.lock".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            ),
+        ];
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .returning(move |_, _| Ok(changes.clone()));
+        mock_repo.expect_get_file_at_head().returning(|_| Ok(None));
+
+        let excludes = ExcludePatterns::new(vec!["Cargo.lock".to_string()]).unwrap();
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_excludes(excludes);
+        let (files, hidden) = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "src/main.rs");
+        assert_eq!(hidden.excluded, 1);
+    }
+
+    fn mock_with_ignore_file(ignore_file: &'static str) -> MockTestGitRepository {
+        let mut mock_repo = MockTestGitRepository::new();
+        let changes = vec![
+            create_test_file_change(),
+            FileChange::new(
+                FilePath::new("web/package-lock.json".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            ),
+            FileChange::new(
+                FilePath::new("src/schema.rs".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            )
+            .with_generated(),
+        ];
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .returning(move |_, _| Ok(changes.clone()));
+        mock_repo
+            .expect_get_file_at_head()
+            .withf(|path| path.as_str() == ".arlonignore")
+            .returning(move |_| Ok(Some(ignore_file.to_string())));
+        mock_repo
+    }
+
+    #[test]
+    fn test_execute_hides_ignored_and_generated_files() {
+        let mock_repo = mock_with_ignore_file("# Lockfiles\npackage-lock.json\n");
+
+        let use_case = CompareFilesUseCase::new(&mock_repo);
+        let (files, hidden) = use_case.execute("main".to_string()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "src/main.rs");
+        assert_eq!(
+            hidden,
+            HiddenFilesDto {
+                excluded: 1,
+                generated: 1,
+            }
+        );
+
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_show_generated(true);
+        let (files, hidden) = use_case.execute("main".to_string()).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files[1].generated);
+        assert_eq!(hidden.total(), 1);
+    }
+
+    #[test]
+    fn test_execute_invalid_ignore_file() {
+        let mock_repo = mock_with_ignore_file("src/[\n");
+
+        let use_case = CompareFilesUseCase::new(&mock_repo);
+        let result = use_case.execute("main".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CompareFilesError::InvalidIgnoreFile(_)
+        ));
+    }
+
+    #[test]
+    fn test_execute_dirstat() {
+        let mut mock_repo = MockTestGitRepository::new();
+        let changes = vec![
+            FileChange::new(
+                FilePath::new("src/lib.rs".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            )
+            .with_lines(LineStats::new(3, 0)),
+            FileChange::new(
+                FilePath::new("README.md".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            )
+            .with_lines(LineStats::new(0, 1)),
+            FileChange::new(
+                FilePath::new("Cargo.lock".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            )
+            .with_lines(LineStats::new(100, 100)),
+        ];
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .withf(|_, options| options.line_stats)
+            .times(1)
+            .returning(move |_, _| Ok(changes.clone()));
+        mock_repo.expect_get_file_at_head().returning(|_| Ok(None));
+
+        let excludes = ExcludePatterns::new(vec!["Cargo.lock".to_string()]).unwrap();
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_excludes(excludes);
+        let (directories, _) = use_case.execute_dirstat("main".to_string(), 1).unwrap();
+
+        assert_eq!(directories.len(), 2);
+        assert_eq!(directories[0].directory, "./");
+        assert_eq!(directories[0].percentage, 25.0);
+        assert_eq!(directories[1].directory, "src/");
+        assert_eq!(directories[1].files, 1);
+        assert_eq!(directories[1].percentage, 75.0);
+    }
+
+    fn mock_with_owners(codeowners: Option<&'static str>) -> MockTestGitRepository {
+        let mut mock_repo = MockTestGitRepository::new();
+        let changes = vec![
+            FileChange::new(
+                FilePath::new("core/src/lib.rs".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            ),
+            FileChange::new(
+                FilePath::new("README.md".to_string()).unwrap(),
+                FileChangeStatus::Modified,
+            ),
+        ];
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .returning(move |_, _| Ok(changes.clone()));
+        mock_repo.expect_get_file_at_head().returning(move |path| {
+            match (path.as_str(), codeowners) {
+                (".github/CODEOWNERS", Some(contents)) => Ok(Some(contents.to_string())),
+                _ => Ok(None),
+            }
+        });
+        mock_repo
+    }
+
+    #[test]
+    fn test_execute_with_owners() {
+        let mock_repo = mock_with_owners(Some("/core/ @backend @leads\n"));
+
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_owners(true);
+        let (files, _) = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(
+            files[0].owners,
+            Some(vec!["@backend".to_string(), "@leads".to_string()])
+        );
+        assert_eq!(files[1].owners, Some(vec![]));
+    }
+
+    #[test]
+    fn test_execute_by_owner() {
+        let mock_repo = mock_with_owners(Some("/core/ @backend @leads\n"));
+
+        let use_case = CompareFilesUseCase::new(&mock_repo);
+        let (groups, _) = use_case.execute_by_owner("main".to_string()).unwrap();
+
+        let summary: Vec<(Option<&str>, Vec<&str>)> = groups
+            .iter()
+            .map(|group| {
+                (
+                    group.owner.as_deref(),
+                    group.files.iter().map(|file| file.path.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("@backend"), vec!["core/src/lib.rs"]),
+                (Some("@leads"), vec!["core/src/lib.rs"]),
+                (None, vec!["README.md"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_with_owners_without_codeowners() {
+        let mock_repo = mock_with_owners(None);
+
+        let use_case = CompareFilesUseCase::new(&mock_repo).with_owners(true);
+        let result = use_case.execute("main".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CompareFilesError::CodeOwnersNotFound
+        ));
+    }
 }
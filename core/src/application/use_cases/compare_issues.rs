@@ -0,0 +1,175 @@
+use crate::application::dto::{CommitDto, IssueDto};
+use crate::application::use_cases::CompareCommitsUseCase;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::services::IssueReferenceDomainService;
+use crate::domain::value_objects::{BranchName, BranchNameError, DateFormat, IssuePatterns};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareIssuesError {
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+}
+
+impl CompareIssuesError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+pub struct CompareIssuesUseCase<'a, R> {
+    git_repository: &'a R,
+    date_format: DateFormat,
+    issue_patterns: IssuePatterns,
+}
+
+impl<'a, R: GitRepository> CompareIssuesUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self {
+            git_repository,
+            date_format: DateFormat::default(),
+            issue_patterns: IssuePatterns::default(),
+        }
+    }
+
+    pub fn with_date_format(mut self, date_format: DateFormat) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    pub fn with_issue_patterns(mut self, issue_patterns: IssuePatterns) -> Self {
+        self.issue_patterns = issue_patterns;
+        self
+    }
+
+    /// Issues referenced by the commits in HEAD that are not in
+    /// `branch_name`, each with the commits referencing it.
+    pub fn execute(&self, branch_name: String) -> Result<Vec<IssueDto>, CompareIssuesError> {
+        let branch = BranchName::new(branch_name)?;
+        let commits =
+            CompareCommitsUseCase::new(self.git_repository).commits_not_in_branch(&branch)?;
+
+        Ok(
+            IssueReferenceDomainService::group(commits, &self.issue_patterns)
+                .into_iter()
+                .map(|references| IssueDto {
+                    issue: references.issue().to_string(),
+                    commits: references
+                        .into_commits()
+                        .into_iter()
+                        .map(|commit| {
+                            let issues = IssueReferenceDomainService::references(
+                                &commit,
+                                &self.issue_patterns,
+                            );
+                            CommitDto::from_commit(commit, &self.date_format).with_issues(issues)
+                        })
+                        .collect(),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Commit;
+    use crate::domain::value_objects::CommitHash;
+    use mockall::mock;
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    fn commit(hash: char, summary: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_execute_groups_commits_by_issue() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(|_, _| {
+                Ok(vec![
+                    commit('c', "PROJ-2: finish export"),
+                    commit('b', "PROJ-2: start export (#7)"),
+                ])
+            });
+
+        let use_case = CompareIssuesUseCase::new(&mock_repo);
+        let issues = use_case.execute("main".to_string()).unwrap();
+
+        let summary: Vec<(&str, usize)> = issues
+            .iter()
+            .map(|issue| (issue.issue.as_str(), issue.commits.len()))
+            .collect();
+        assert_eq!(summary, vec![("#7", 1), ("PROJ-2", 2)]);
+        assert_eq!(issues[1].commits[0].message, "PROJ-2: finish export");
+        assert_eq!(issues[0].commits[0].issues, vec!["PROJ-2", "#7"]);
+    }
+
+    #[test]
+    fn test_execute_invalid_branch_name() {
+        let mock_repo = MockTestGitRepository::new();
+        let use_case = CompareIssuesUseCase::new(&mock_repo);
+
+        let result = use_case.execute("a..b".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CompareIssuesError::InvalidBranchName(_)
+        ));
+    }
+}
@@ -0,0 +1,459 @@
+use crate::application::dto::PackageDto;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::value_objects::{
+    BranchName, BranchNameError, CommitHash, FileComparisonOptions, FilePath, FilePathError,
+    PackageGlobs, PackageManager, WorkspaceMembers, WorkspaceMembersError,
+};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DetectAffectedPackagesError {
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+    #[error(transparent)]
+    InvalidWorkspace(#[from] WorkspaceMembersError),
+    #[error("Invalid manifest path: {0}")]
+    InvalidManifestPath(#[from] FilePathError),
+    #[error(
+        "No Cargo or npm workspace found in HEAD; define packages in the `packages` config table"
+    )]
+    WorkspaceNotFound,
+}
+
+impl DetectAffectedPackagesError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::InvalidWorkspace(_) => "invalid_workspace",
+            Self::InvalidManifestPath(_) => "invalid_manifest_path",
+            Self::WorkspaceNotFound => "workspace_not_found",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+/// Package directory and manager, mapped to the package name or `None`
+/// when the directory has no manifest in HEAD or the merge base.
+type ManifestCache = HashMap<(String, PackageManager), Option<String>>;
+
+pub struct DetectAffectedPackagesUseCase<'a, R> {
+    git_repository: &'a R,
+    options: FileComparisonOptions,
+    packages: PackageGlobs,
+}
+
+impl<'a, R: GitRepository> DetectAffectedPackagesUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self {
+            git_repository,
+            options: FileComparisonOptions::default(),
+            packages: PackageGlobs::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: FileComparisonOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Packages defined in config. When set, workspace manifests are not
+    /// consulted.
+    pub fn with_packages(mut self, packages: PackageGlobs) -> Self {
+        self.packages = packages;
+        self
+    }
+
+    /// Packages containing at least one changed file, sorted by name.
+    pub fn execute(
+        &self,
+        branch_name: String,
+    ) -> Result<Vec<PackageDto>, DetectAffectedPackagesError> {
+        let branch = BranchName::new(branch_name)?;
+        let file_changes = self
+            .git_repository
+            .get_file_changes_between_branches(&branch, &self.options)?;
+        let paths: Vec<&FilePath> = file_changes.iter().map(|change| change.path()).collect();
+
+        if !self.packages.is_empty() {
+            return Ok(self.configured_packages(&paths));
+        }
+
+        let merge_base = self
+            .git_repository
+            .resolve_endpoints(&branch, None)?
+            .merge_base;
+        let workspaces = self.workspaces(merge_base.as_ref())?;
+        if workspaces.is_empty() {
+            return Err(DetectAffectedPackagesError::WorkspaceNotFound);
+        }
+
+        let mut manifests = ManifestCache::new();
+        let mut affected: BTreeMap<(String, PackageManager), (String, usize)> = BTreeMap::new();
+        for path in paths {
+            let Some((directory, manager, name)) =
+                self.package_of(path, &workspaces, merge_base.as_ref(), &mut manifests)?
+            else {
+                continue;
+            };
+            affected.entry((directory, manager)).or_insert((name, 0)).1 += 1;
+        }
+
+        let mut packages: Vec<PackageDto> = affected
+            .into_iter()
+            .map(|((directory, manager), (name, files))| PackageDto {
+                name,
+                path: Some(display_directory(&directory).to_string()),
+                source: manager.as_str().to_string(),
+                files,
+            })
+            .collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path)));
+        Ok(packages)
+    }
+
+    fn configured_packages(&self, paths: &[&FilePath]) -> Vec<PackageDto> {
+        let mut affected: BTreeMap<&str, usize> = BTreeMap::new();
+        for path in paths {
+            for package in self.packages.packages_of(path) {
+                *affected.entry(package).or_default() += 1;
+            }
+        }
+
+        affected
+            .into_iter()
+            .map(|(name, files)| PackageDto {
+                name: name.to_string(),
+                path: None,
+                source: "config".to_string(),
+                files,
+            })
+            .collect()
+    }
+
+    /// Workspaces declared by the root manifests in HEAD and the merge
+    /// base, so members the branch removes still match their files.
+    fn workspaces(
+        &self,
+        merge_base: Option<&CommitHash>,
+    ) -> Result<Vec<WorkspaceMembers>, DetectAffectedPackagesError> {
+        let mut workspaces = Vec::new();
+
+        for manager in PackageManager::ALL {
+            let path = FilePath::new(manager.manifest().to_string())?;
+            if let Some(manifest) = self.git_repository.get_file_at_head(&path)? {
+                workspaces.extend(WorkspaceMembers::parse(manager, &manifest)?);
+            }
+            if let Some(merge_base) = merge_base {
+                if let Some(manifest) = self.git_repository.get_file_at_commit(merge_base, &path)? {
+                    workspaces.extend(WorkspaceMembers::parse(manager, &manifest)?);
+                }
+            }
+        }
+
+        Ok(workspaces)
+    }
+
+    /// The innermost workspace member containing `path` that has a
+    /// manifest in HEAD, or in the merge base for a package the branch
+    /// deletes or moves.
+    fn package_of(
+        &self,
+        path: &FilePath,
+        workspaces: &[WorkspaceMembers],
+        merge_base: Option<&CommitHash>,
+        manifests: &mut ManifestCache,
+    ) -> Result<Option<(String, PackageManager, String)>, DetectAffectedPackagesError> {
+        let path = path.as_str();
+        let mut directories: Vec<&str> = path.match_indices('/').map(|(i, _)| &path[..i]).collect();
+        directories.reverse();
+        directories.push("");
+
+        for directory in directories {
+            for workspace in workspaces {
+                if !workspace.is_member(directory) {
+                    continue;
+                }
+
+                let manager = workspace.manager();
+                let key = (directory.to_string(), manager);
+                if !manifests.contains_key(&key) {
+                    let name = self.package_name(directory, manager, merge_base)?;
+                    manifests.insert(key.clone(), name);
+                }
+                if let Some(Some(name)) = manifests.get(&key) {
+                    return Ok(Some((directory.to_string(), manager, name.clone())));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The name from the manifest in `directory` at HEAD, else at the merge
+    /// base, falling back to the directory when the manifest has no name.
+    /// `None` without a manifest.
+    fn package_name(
+        &self,
+        directory: &str,
+        manager: PackageManager,
+        merge_base: Option<&CommitHash>,
+    ) -> Result<Option<String>, DetectAffectedPackagesError> {
+        let manifest_path = if directory.is_empty() {
+            manager.manifest().to_string()
+        } else {
+            format!("{}/{}", directory, manager.manifest())
+        };
+        let manifest_path = FilePath::new(manifest_path)?;
+
+        let manifest = match self.git_repository.get_file_at_head(&manifest_path)? {
+            Some(manifest) => Some(manifest),
+            None => match merge_base {
+                Some(merge_base) => self
+                    .git_repository
+                    .get_file_at_commit(merge_base, &manifest_path)?,
+                None => None,
+            },
+        };
+
+        Ok(manifest.map(|manifest| {
+            manager
+                .package_name(&manifest)
+                .unwrap_or_else(|| display_directory(directory).to_string())
+        }))
+    }
+}
+
+fn display_directory(directory: &str) -> &str {
+    if directory.is_empty() {
+        "."
+    } else {
+        directory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{FileChange, FileChangeStatus};
+    use crate::domain::value_objects::ComparisonEndpoints;
+    use mockall::mock;
+
+    const BASE: &str = "1111111111111111111111111111111111111111";
+    const MERGE_BASE: &str = "2222222222222222222222222222222222222222";
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<crate::domain::entities::Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &FileComparisonOptions,
+            ) -> Result<Vec<FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    fn mock_repository(
+        changed: &[&'static str],
+        files: &'static [(&'static str, &'static str)],
+    ) -> MockTestGitRepository {
+        mock_repository_with_merge_base(changed, files, &[])
+    }
+
+    /// A repository where `files` are in HEAD and `merge_base_files` in the
+    /// merge base of HEAD and the base branch.
+    fn mock_repository_with_merge_base(
+        changed: &[&'static str],
+        files: &'static [(&'static str, &'static str)],
+        merge_base_files: &'static [(&'static str, &'static str)],
+    ) -> MockTestGitRepository {
+        let mut mock_repo = MockTestGitRepository::new();
+        let changes: Vec<FileChange> = changed
+            .iter()
+            .map(|path| {
+                FileChange::new(
+                    FilePath::new(path.to_string()).unwrap(),
+                    FileChangeStatus::Modified,
+                )
+            })
+            .collect();
+
+        mock_repo
+            .expect_get_file_changes_between_branches()
+            .returning(move |_, _| Ok(changes.clone()));
+        mock_repo.expect_get_file_at_head().returning(move |path| {
+            Ok(files
+                .iter()
+                .find(|(name, _)| *name == path.as_str())
+                .map(|(_, contents)| contents.to_string()))
+        });
+        mock_repo.expect_resolve_endpoints().returning(|_, _| {
+            Ok(ComparisonEndpoints {
+                base: CommitHash::new(BASE.to_string()).unwrap(),
+                target: CommitHash::new(BASE.to_string()).unwrap(),
+                merge_base: Some(CommitHash::new(MERGE_BASE.to_string()).unwrap()),
+            })
+        });
+        mock_repo
+            .expect_get_file_at_commit()
+            .returning(move |commit, path| {
+                assert_eq!(commit.as_str(), MERGE_BASE);
+                Ok(merge_base_files
+                    .iter()
+                    .find(|(name, _)| *name == path.as_str())
+                    .map(|(_, contents)| contents.to_string()))
+            });
+        mock_repo
+    }
+
+    #[test]
+    fn test_execute_cargo_and_npm_workspaces() {
+        let mock_repo = mock_repository(
+            &[
+                "crates/parser/src/lib.rs",
+                "crates/parser/Cargo.toml",
+                "web/app/src/index.ts",
+                "README.md",
+            ],
+            &[
+                ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+                (
+                    "crates/parser/Cargo.toml",
+                    "[package]\nname = \"acme-parser\"\n",
+                ),
+                ("package.json", r#"{"workspaces": ["web/*"]}"#),
+                ("web/app/package.json", r#"{"name": "@acme/app"}"#),
+            ],
+        );
+
+        let use_case = DetectAffectedPackagesUseCase::new(&mock_repo);
+        let packages = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(
+            packages,
+            vec![
+                PackageDto {
+                    name: "@acme/app".to_string(),
+                    path: Some("web/app".to_string()),
+                    source: "npm".to_string(),
+                    files: 1,
+                },
+                PackageDto {
+                    name: "acme-parser".to_string(),
+                    path: Some("crates/parser".to_string()),
+                    source: "cargo".to_string(),
+                    files: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_skips_members_without_manifest() {
+        let mock_repo = mock_repository(
+            &["crates/README.md", "crates/docs/guide.md"],
+            &[("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n")],
+        );
+
+        let use_case = DetectAffectedPackagesUseCase::new(&mock_repo);
+        let packages = use_case.execute("main".to_string()).unwrap();
+
+        assert!(packages.is_empty());
+    }
+
+    #[test]
+    fn test_execute_deleted_package() {
+        let mock_repo = mock_repository_with_merge_base(
+            &["crates/legacy/src/lib.rs", "crates/legacy/Cargo.toml"],
+            &[("Cargo.toml", "[workspace]\nmembers = [\"crates/parser\"]\n")],
+            &[
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"crates/parser\", \"crates/legacy\"]\n",
+                ),
+                (
+                    "crates/legacy/Cargo.toml",
+                    "[package]\nname = \"acme-legacy\"\n",
+                ),
+            ],
+        );
+
+        let use_case = DetectAffectedPackagesUseCase::new(&mock_repo);
+        let packages = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(
+            packages,
+            vec![PackageDto {
+                name: "acme-legacy".to_string(),
+                path: Some("crates/legacy".to_string()),
+                source: "cargo".to_string(),
+                files: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_execute_with_configured_packages() {
+        let mock_repo = mock_repository(&["apps/web/index.ts", "docs/index.md"], &[]);
+        let packages = PackageGlobs::new(BTreeMap::from([(
+            "web".to_string(),
+            vec!["apps/web".to_string()],
+        )]))
+        .unwrap();
+
+        let use_case = DetectAffectedPackagesUseCase::new(&mock_repo).with_packages(packages);
+        let packages = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, "web");
+        assert_eq!(packages[0].path, None);
+        assert_eq!(packages[0].source, "config");
+    }
+
+    #[test]
+    fn test_execute_without_workspace() {
+        let mock_repo = mock_repository(&["src/main.rs"], &[("Cargo.toml", "[package]\n")]);
+
+        let use_case = DetectAffectedPackagesUseCase::new(&mock_repo);
+        let result = use_case.execute("main".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            DetectAffectedPackagesError::WorkspaceNotFound
+        ));
+    }
+}
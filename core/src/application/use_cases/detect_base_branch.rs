@@ -0,0 +1,124 @@
+use crate::application::dto::BaseBranchDto;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::services::BaseBranchDomainService;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DetectBaseBranchError {
+    #[error("Could not detect a base branch: HEAD has no upstream, origin/HEAD is not set and neither main, master, origin/main nor origin/master exists")]
+    NotDetected,
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+}
+
+impl DetectBaseBranchError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NotDetected => "base_branch_not_detected",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+pub struct DetectBaseBranchUseCase<'a, R> {
+    git_repository: &'a R,
+}
+
+impl<'a, R: GitRepository> DetectBaseBranchUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self { git_repository }
+    }
+
+    pub fn execute(&self) -> Result<BaseBranchDto, DetectBaseBranchError> {
+        let candidates = self.git_repository.get_base_branch_candidates()?;
+
+        BaseBranchDomainService::select(candidates)
+            .map(|(branch, source)| BaseBranchDto::new(branch, source))
+            .ok_or(DetectBaseBranchError::NotDetected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{BaseBranchCandidates, Commit, FileChange};
+    use crate::domain::value_objects::{BranchName, FileComparisonOptions};
+    use mockall::mock;
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &FileComparisonOptions,
+            ) -> Result<Vec<FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(&self) -> Result<BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    #[test]
+    fn test_execute_detects_upstream() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_base_branch_candidates()
+            .times(1)
+            .returning(|| {
+                Ok(BaseBranchCandidates {
+                    upstream: Some(BranchName::new("origin/feature".to_string()).unwrap()),
+                    ..BaseBranchCandidates::default()
+                })
+            });
+
+        let use_case = DetectBaseBranchUseCase::new(&mock_repo);
+        let base = use_case.execute().unwrap();
+
+        assert_eq!(base.branch, "origin/feature");
+        assert_eq!(base.source, "upstream of HEAD");
+    }
+
+    #[test]
+    fn test_execute_not_detected() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_base_branch_candidates()
+            .returning(|| Ok(BaseBranchCandidates::default()));
+
+        let use_case = DetectBaseBranchUseCase::new(&mock_repo);
+        let result = use_case.execute();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            DetectBaseBranchError::NotDetected
+        ));
+    }
+}
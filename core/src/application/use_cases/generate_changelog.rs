@@ -0,0 +1,181 @@
+use crate::application::dto::ChangelogDto;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::services::ChangelogDomainService;
+use crate::domain::value_objects::{BranchName, BranchNameError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenerateChangelogError {
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+}
+
+impl GenerateChangelogError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+pub struct GenerateChangelogUseCase<'a, R> {
+    git_repository: &'a R,
+}
+
+impl<'a, R: GitRepository> GenerateChangelogUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self { git_repository }
+    }
+
+    /// Changelog of the commits reachable from `to` (HEAD when `None`) but
+    /// not from `from`.
+    pub fn execute(
+        &self,
+        from: String,
+        to: Option<String>,
+    ) -> Result<ChangelogDto, GenerateChangelogError> {
+        let from_branch = BranchName::new(from)?;
+        let to_branch = to.map(BranchName::new).transpose()?;
+        let commits = self
+            .git_repository
+            .get_commits_not_in_branch(&from_branch, to_branch)?;
+        let changelog = ChangelogDomainService::build(&commits);
+
+        Ok(ChangelogDto::from_changelog(
+            &changelog,
+            from_branch.as_str(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Commit;
+    use crate::domain::value_objects::CommitHash;
+    use mockall::mock;
+    use mockall::predicate::*;
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    fn commit(hash: char, summary: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_execute_from_tag_to_head() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .with(
+                function(|branch: &BranchName| branch.as_str() == "v1.2.0"),
+                function(|target: &Option<BranchName>| target.is_none()),
+            )
+            .returning(|_, _| {
+                Ok(vec![
+                    commit('c', "feat: add changelog"),
+                    commit('b', "fix: typo"),
+                ])
+            });
+
+        let use_case = GenerateChangelogUseCase::new(&mock_repo);
+        let changelog = use_case.execute("v1.2.0".to_string(), None).unwrap();
+
+        assert_eq!(changelog.bump, "minor");
+        assert_eq!(changelog.next_version.as_deref(), Some("v1.3.0"));
+        let titles: Vec<&str> = changelog
+            .sections
+            .iter()
+            .map(|section| section.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Features", "Fixes"]);
+    }
+
+    #[test]
+    fn test_execute_with_target() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .with(
+                function(|branch: &BranchName| branch.as_str() == "main"),
+                function(|target: &Option<BranchName>| {
+                    target
+                        .as_ref()
+                        .is_some_and(|target| target.as_str() == "release")
+                }),
+            )
+            .returning(|_, _| Ok(vec![commit('b', "fix!: reject empty names")]));
+
+        let use_case = GenerateChangelogUseCase::new(&mock_repo);
+        let changelog = use_case
+            .execute("main".to_string(), Some("release".to_string()))
+            .unwrap();
+
+        assert_eq!(changelog.bump, "major");
+        assert_eq!(changelog.next_version, None);
+        assert_eq!(changelog.sections[0].title, "Breaking Changes");
+    }
+
+    #[test]
+    fn test_execute_invalid_branch_name() {
+        let mock_repo = MockTestGitRepository::new();
+        let use_case = GenerateChangelogUseCase::new(&mock_repo);
+
+        let result = use_case.execute("a..b".to_string(), None);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            GenerateChangelogError::InvalidBranchName(_)
+        ));
+    }
+}
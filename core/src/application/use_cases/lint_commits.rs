@@ -0,0 +1,192 @@
+use crate::application::dto::{CommitDto, LintDto, LintViolationDto};
+use crate::application::use_cases::CompareCommitsUseCase;
+use crate::domain::repositories::{GitRepository, GitRepositoryError};
+use crate::domain::services::{CommitLintDomainService, IssueReferenceDomainService};
+use crate::domain::value_objects::{
+    BranchName, BranchNameError, DateFormat, IssuePatterns, LintRules,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LintCommitsError {
+    #[error("Invalid branch name: {0}")]
+    InvalidBranchName(#[from] BranchNameError),
+    #[error("Git repository error: {0}")]
+    RepositoryError(#[from] GitRepositoryError),
+}
+
+impl LintCommitsError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBranchName(_) => "invalid_branch_name",
+            Self::RepositoryError(e) => e.kind(),
+        }
+    }
+}
+
+pub struct LintCommitsUseCase<'a, R> {
+    git_repository: &'a R,
+    date_format: DateFormat,
+    issue_patterns: IssuePatterns,
+    rules: LintRules,
+}
+
+impl<'a, R: GitRepository> LintCommitsUseCase<'a, R> {
+    pub fn new(git_repository: &'a R) -> Self {
+        Self {
+            git_repository,
+            date_format: DateFormat::default(),
+            issue_patterns: IssuePatterns::default(),
+            rules: LintRules::default(),
+        }
+    }
+
+    pub fn with_date_format(mut self, date_format: DateFormat) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    pub fn with_issue_patterns(mut self, issue_patterns: IssuePatterns) -> Self {
+        self.issue_patterns = issue_patterns;
+        self
+    }
+
+    pub fn with_rules(mut self, rules: LintRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// The commits in HEAD that are not in `branch_name` and break at least
+    /// one rule, newest first.
+    pub fn execute(&self, branch_name: String) -> Result<Vec<LintDto>, LintCommitsError> {
+        let branch = BranchName::new(branch_name)?;
+        let commits =
+            CompareCommitsUseCase::new(self.git_repository).commits_not_in_branch(&branch)?;
+
+        Ok(commits
+            .into_iter()
+            .filter_map(|commit| {
+                let violations = CommitLintDomainService::violations(&commit, &self.rules);
+                if violations.is_empty() {
+                    return None;
+                }
+
+                let issues = IssueReferenceDomainService::references(&commit, &self.issue_patterns);
+                Some(LintDto {
+                    commit: CommitDto::from_commit(commit, &self.date_format).with_issues(issues),
+                    violations: violations.iter().map(LintViolationDto::from).collect(),
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::Commit;
+    use crate::domain::value_objects::CommitHash;
+    use mockall::mock;
+
+    mock! {
+        TestGitRepository {}
+
+        impl GitRepository for TestGitRepository {
+            fn get_commits_not_in_branch(&self, branch: &BranchName, target: Option<BranchName>) -> Result<Vec<Commit>, GitRepositoryError>;
+            fn resolve_endpoints(&self, branch: &BranchName, target: Option<BranchName>) -> Result<crate::domain::value_objects::ComparisonEndpoints, GitRepositoryError>;
+
+            fn get_file_changes_between_branches(
+                &self,
+                branch: &BranchName,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+            fn get_commit_file_changes(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                options: &crate::domain::value_objects::FileComparisonOptions,
+            ) -> Result<Vec<crate::domain::entities::FileChange>, GitRepositoryError>;
+
+            fn get_commit_line_stats(
+                &self,
+                commit: &CommitHash,
+            ) -> Result<crate::domain::value_objects::LineStats, GitRepositoryError>;
+
+            fn get_file_at_head(
+                &self,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+            fn get_file_at_commit(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                path: &crate::domain::value_objects::FilePath,
+            ) -> Result<Option<String>, GitRepositoryError>;
+
+            fn get_base_branch_candidates(
+                &self,
+            ) -> Result<crate::domain::entities::BaseBranchCandidates, GitRepositoryError>;
+            fn verify_commit_signature(
+                &self,
+                commit: &crate::domain::value_objects::CommitHash,
+                verification: &crate::domain::value_objects::SignatureVerification,
+            ) -> Result<crate::domain::value_objects::SignatureStatus, GitRepositoryError>;
+        }
+    }
+
+    fn commit(hash: char, summary: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_execute_reports_violating_commits() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(|_, _| Ok(vec![commit('c', "WIP"), commit('b', "Add lint command")]));
+
+        let use_case = LintCommitsUseCase::new(&mock_repo);
+        let results = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].commit.message, "WIP");
+        assert_eq!(
+            results[0].violations,
+            vec![LintViolationDto {
+                rule: "no-wip".to_string(),
+                message: "Work-in-progress commit".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_execute_with_rules() {
+        let mut mock_repo = MockTestGitRepository::new();
+        mock_repo
+            .expect_get_commits_not_in_branch()
+            .returning(|_, _| Ok(vec![commit('b', "Add lint command")]));
+
+        let rules = LintRules::new(Some(vec!["conventional-commits".to_string()]), 72).unwrap();
+        let use_case = LintCommitsUseCase::new(&mock_repo).with_rules(rules);
+        let results = use_case.execute("main".to_string()).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].violations[0].rule, "conventional-commits");
+    }
+
+    #[test]
+    fn test_execute_invalid_branch_name() {
+        let mock_repo = MockTestGitRepository::new();
+        let use_case = LintCommitsUseCase::new(&mock_repo);
+
+        let result = use_case.execute("a..b".to_string());
+
+        assert!(matches!(
+            result.unwrap_err(),
+            LintCommitsError::InvalidBranchName(_)
+        ));
+    }
+}
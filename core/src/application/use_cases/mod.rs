@@ -1,5 +1,17 @@
+pub mod compare_authors;
 pub mod compare_commits;
 pub mod compare_files;
+pub mod compare_issues;
+pub mod detect_affected_packages;
+pub mod detect_base_branch;
+pub mod generate_changelog;
+pub mod lint_commits;
 
+pub use compare_authors::CompareAuthorsUseCase;
 pub use compare_commits::CompareCommitsUseCase;
 pub use compare_files::CompareFilesUseCase;
+pub use compare_issues::CompareIssuesUseCase;
+pub use detect_affected_packages::DetectAffectedPackagesUseCase;
+pub use detect_base_branch::DetectBaseBranchUseCase;
+pub use generate_changelog::GenerateChangelogUseCase;
+pub use lint_commits::LintCommitsUseCase;
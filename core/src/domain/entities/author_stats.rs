@@ -0,0 +1,75 @@
+use crate::domain::entities::Commit;
+use crate::domain::value_objects::LineStats;
+
+/// Commits by one author, keyed by name and email after mailmap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorStats {
+    name: String,
+    email: String,
+    commit_count: usize,
+    first_timestamp: i64,
+    first_offset_minutes: i32,
+    last_timestamp: i64,
+    last_offset_minutes: i32,
+    lines: Option<LineStats>,
+}
+
+impl AuthorStats {
+    pub fn new(commit: &Commit, lines: Option<LineStats>) -> Self {
+        Self {
+            name: commit.author().to_string(),
+            email: commit.email().to_string(),
+            commit_count: 1,
+            first_timestamp: commit.timestamp(),
+            first_offset_minutes: commit.offset_minutes(),
+            last_timestamp: commit.timestamp(),
+            last_offset_minutes: commit.offset_minutes(),
+            lines,
+        }
+    }
+
+    /// Adds another commit by the same author.
+    pub fn record(&mut self, commit: &Commit, lines: Option<LineStats>) {
+        self.commit_count += 1;
+        if commit.timestamp() < self.first_timestamp {
+            self.first_timestamp = commit.timestamp();
+            self.first_offset_minutes = commit.offset_minutes();
+        }
+        if commit.timestamp() > self.last_timestamp {
+            self.last_timestamp = commit.timestamp();
+            self.last_offset_minutes = commit.offset_minutes();
+        }
+        if let (Some(total), Some(lines)) = (self.lines.as_mut(), lines) {
+            *total += lines;
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn commit_count(&self) -> usize {
+        self.commit_count
+    }
+
+    pub fn first_timestamp(&self) -> i64 {
+        self.first_timestamp
+    }
+
+    pub fn last_timestamp(&self) -> i64 {
+        self.last_timestamp
+    }
+
+    /// The timezones of the first and last commits, in minutes east of UTC.
+    pub fn offsets_minutes(&self) -> (i32, i32) {
+        (self.first_offset_minutes, self.last_offset_minutes)
+    }
+
+    pub fn lines(&self) -> Option<LineStats> {
+        self.lines
+    }
+}
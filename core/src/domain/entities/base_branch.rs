@@ -0,0 +1,29 @@
+use crate::domain::value_objects::BranchName;
+
+/// Branches that can serve as the comparison base when none is given.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BaseBranchCandidates {
+    pub upstream: Option<BranchName>,
+    pub remote_default: Option<BranchName>,
+    pub local_defaults: Vec<BranchName>,
+    pub remote_defaults: Vec<BranchName>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseBranchSource {
+    Upstream,
+    RemoteDefault,
+    LocalDefault,
+    RemoteBranch,
+}
+
+impl BaseBranchSource {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Upstream => "upstream of HEAD",
+            Self::RemoteDefault => "default branch of origin",
+            Self::LocalDefault => "default local branch",
+            Self::RemoteBranch => "default branch name on origin",
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::domain::value_objects::{CommitHash, VersionBump};
+
+/// Sections of a changelog, in the order they are presented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangelogSection {
+    BreakingChanges,
+    Features,
+    Fixes,
+}
+
+impl ChangelogSection {
+    pub const ALL: [ChangelogSection; 3] = [
+        ChangelogSection::BreakingChanges,
+        ChangelogSection::Features,
+        ChangelogSection::Fixes,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::BreakingChanges => "Breaking Changes",
+            Self::Features => "Features",
+            Self::Fixes => "Fixes",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangelogEntry {
+    hash: CommitHash,
+    scope: Option<String>,
+    description: String,
+}
+
+impl ChangelogEntry {
+    pub fn new(hash: CommitHash, scope: Option<String>, description: String) -> Self {
+        Self {
+            hash,
+            scope,
+            description,
+        }
+    }
+
+    pub fn hash(&self) -> &CommitHash {
+        &self.hash
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Changelog {
+    breaking_changes: Vec<ChangelogEntry>,
+    features: Vec<ChangelogEntry>,
+    fixes: Vec<ChangelogEntry>,
+    bump: VersionBump,
+}
+
+impl Changelog {
+    pub fn push(&mut self, section: ChangelogSection, entry: ChangelogEntry) {
+        let bump = match section {
+            ChangelogSection::BreakingChanges => VersionBump::Major,
+            ChangelogSection::Features => VersionBump::Minor,
+            ChangelogSection::Fixes => VersionBump::Patch,
+        };
+        self.bump = self.bump.max(bump);
+
+        match section {
+            ChangelogSection::BreakingChanges => self.breaking_changes.push(entry),
+            ChangelogSection::Features => self.features.push(entry),
+            ChangelogSection::Fixes => self.fixes.push(entry),
+        }
+    }
+
+    pub fn entries(&self, section: ChangelogSection) -> &[ChangelogEntry] {
+        match section {
+            ChangelogSection::BreakingChanges => &self.breaking_changes,
+            ChangelogSection::Features => &self.features,
+            ChangelogSection::Fixes => &self.fixes,
+        }
+    }
+
+    /// The smallest version bump that covers every entry.
+    pub fn bump(&self) -> VersionBump {
+        self.bump
+    }
+}
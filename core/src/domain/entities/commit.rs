@@ -1,5 +1,9 @@
-use crate::domain::value_objects::CommitHash;
-use chrono::DateTime;
+use crate::domain::value_objects::{
+    CommitHash, ConventionalCommit, DateFormat, SignatureFormat, Trailer,
+};
+
+/// Summary prefixes `git rebase --autosquash` folds into an earlier commit.
+const AUTOSQUASH_PREFIXES: [&str; 3] = ["fixup!", "squash!", "amend!"];
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Commit {
@@ -7,7 +11,11 @@ pub struct Commit {
     author: String,
     email: String,
     timestamp: i64,
+    offset_minutes: i32,
     message: String,
+    body: String,
+    parents: Vec<CommitHash>,
+    signature: Option<SignatureFormat>,
 }
 
 impl Commit {
@@ -23,10 +31,38 @@ impl Commit {
             author,
             email,
             timestamp,
+            offset_minutes: 0,
             message,
+            body: String::new(),
+            parents: Vec::new(),
+            signature: None,
         }
     }
 
+    /// Sets the timezone the commit was made in, in minutes east of UTC.
+    pub fn with_offset_minutes(mut self, offset_minutes: i32) -> Self {
+        self.offset_minutes = offset_minutes;
+        self
+    }
+
+    /// Sets the part of the commit message after the summary line.
+    pub fn with_body(mut self, body: String) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Sets the parent commits, the first parent first.
+    pub fn with_parents(mut self, parents: Vec<CommitHash>) -> Self {
+        self.parents = parents;
+        self
+    }
+
+    /// Marks the commit as signed with a signature of the given format.
+    pub fn with_signature(mut self, signature: SignatureFormat) -> Self {
+        self.signature = Some(signature);
+        self
+    }
+
     pub fn hash(&self) -> &CommitHash {
         &self.hash
     }
@@ -43,13 +79,63 @@ impl Commit {
         self.timestamp
     }
 
+    pub fn offset_minutes(&self) -> i32 {
+        self.offset_minutes
+    }
+
+    /// The summary line of the commit message.
     pub fn message(&self) -> &str {
         &self.message
     }
 
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn parents(&self) -> &[CommitHash] {
+        &self.parents
+    }
+
+    pub fn signature(&self) -> Option<SignatureFormat> {
+        self.signature
+    }
+
+    pub fn is_merge(&self) -> bool {
+        self.parents.len() > 1
+    }
+
+    /// For a `fixup!`, `squash!` or `amend!` commit, the prefix and the
+    /// subject or hash of the commit it amends. Repeated prefixes, as in
+    /// `fixup! fixup! Add parser`, are skipped.
+    pub fn autosquash_target(&self) -> Option<(&'static str, &str)> {
+        let mut subject = self.message.trim();
+        let mut found = None;
+        while let Some(prefix) = AUTOSQUASH_PREFIXES
+            .into_iter()
+            .find(|prefix| subject.starts_with(prefix))
+        {
+            found.get_or_insert(prefix);
+            subject = subject[prefix.len()..].trim_start();
+        }
+        found.map(|prefix| (prefix, subject))
+    }
+
+    /// The message parsed as a Conventional Commit, if it is one.
+    pub fn conventional(&self) -> Option<ConventionalCommit> {
+        ConventionalCommit::parse(&self.message, &self.body)
+    }
+
+    /// The git trailers at the end of the message, in order.
+    pub fn trailers(&self) -> Vec<Trailer> {
+        Trailer::parse_all(&self.body)
+    }
+
     pub fn formatted_date(&self) -> String {
-        let datetime = DateTime::from_timestamp(self.timestamp, 0).unwrap_or_default();
-        datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+        self.format_date(&DateFormat::default())
+    }
+
+    pub fn format_date(&self, date_format: &DateFormat) -> String {
+        date_format.format_timestamp(self.timestamp, self.offset_minutes)
     }
 }
 
@@ -75,6 +161,51 @@ mod tests {
         assert_eq!(commit.email(), "test@example.com");
         assert_eq!(commit.timestamp(), 1634567890);
         assert_eq!(commit.message(), "Test commit message");
+        assert_eq!(commit.body(), "");
+        assert!(commit.trailers().is_empty());
+        assert!(commit.parents().is_empty());
+        assert!(!commit.is_merge());
+    }
+
+    #[test]
+    fn test_autosquash_target() {
+        let with_message = |message: &str| {
+            Commit::new(
+                CommitHash::new("a".repeat(40)).unwrap(),
+                "Test Author".to_string(),
+                "test@example.com".to_string(),
+                1634567890,
+                message.to_string(),
+            )
+        };
+
+        assert_eq!(
+            with_message("fixup! fixup! Add parser").autosquash_target(),
+            Some(("fixup!", "Add parser"))
+        );
+        assert_eq!(
+            with_message("amend! 1234567").autosquash_target(),
+            Some(("amend!", "1234567"))
+        );
+        assert_eq!(
+            with_message("Fix fixup! handling").autosquash_target(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_trailers() {
+        let commit = create_test_commit().with_body(
+            "Details.
+
+Signed-off-by: Test Author <test@example.com>"
+                .to_string(),
+        );
+
+        let trailers = commit.trailers();
+        assert_eq!(trailers.len(), 1);
+        assert_eq!(trailers[0].token(), "Signed-off-by");
+        assert_eq!(trailers[0].value(), "Test Author <test@example.com>");
     }
 
     #[test]
@@ -95,6 +226,16 @@ mod tests {
         assert_eq!(formatted.len(), 19);
     }
 
+    #[test]
+    fn test_format_date() {
+        let commit = create_test_commit();
+        let date_format = DateFormat::new("%d/%m/%Y".to_string()).unwrap();
+        assert_eq!(commit.format_date(&date_format), "18/10/2021");
+
+        let commit = commit.with_offset_minutes(-15 * 60);
+        assert_eq!(commit.format_date(&date_format), "17/10/2021");
+    }
+
     #[test]
     fn test_commit_equality() {
         let commit1 = create_test_commit();
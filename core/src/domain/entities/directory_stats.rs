@@ -0,0 +1,41 @@
+use crate::domain::value_objects::LineStats;
+
+/// Changed files under one directory prefix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectoryStats {
+    directory: String,
+    files: usize,
+    lines: LineStats,
+}
+
+impl DirectoryStats {
+    pub fn new(directory: String) -> Self {
+        Self {
+            directory,
+            files: 0,
+            lines: LineStats::default(),
+        }
+    }
+
+    pub fn record(&mut self, lines: LineStats) {
+        self.files += 1;
+        self.lines += lines;
+    }
+
+    /// Directory prefix with a trailing `/`, or `./` for the root.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    pub fn files(&self) -> usize {
+        self.files
+    }
+
+    pub fn lines(&self) -> LineStats {
+        self.lines
+    }
+
+    pub fn changed_lines(&self) -> usize {
+        self.lines.added + self.lines.removed
+    }
+}
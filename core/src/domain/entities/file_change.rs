@@ -1,9 +1,15 @@
-use crate::domain::value_objects::FilePath;
+use crate::domain::entities::{FilePatch, SubmoduleChange};
+use crate::domain::value_objects::{FilePath, LineStats};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileChange {
     path: FilePath,
     status: FileChangeStatus,
+    lines: Option<LineStats>,
+    patch: Option<FilePatch>,
+    whitespace_only: bool,
+    generated: bool,
+    submodule: Option<SubmoduleChange>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -23,7 +29,42 @@ pub enum FileChangeStatus {
 
 impl FileChange {
     pub fn new(path: FilePath, status: FileChangeStatus) -> Self {
-        Self { path, status }
+        Self {
+            path,
+            status,
+            lines: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        }
+    }
+
+    pub fn with_lines(mut self, lines: LineStats) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    pub fn with_patch(mut self, patch: FilePatch) -> Self {
+        self.patch = Some(patch);
+        self
+    }
+
+    /// Marks the file as differing only in ignored whitespace.
+    pub fn with_whitespace_only(mut self) -> Self {
+        self.whitespace_only = true;
+        self
+    }
+
+    /// Marks the file as generated through `.gitattributes`.
+    pub fn with_generated(mut self) -> Self {
+        self.generated = true;
+        self
+    }
+
+    pub fn with_submodule(mut self, submodule: SubmoduleChange) -> Self {
+        self.submodule = Some(submodule);
+        self
     }
 
     pub fn path(&self) -> &FilePath {
@@ -33,6 +74,35 @@ impl FileChange {
     pub fn status(&self) -> &FileChangeStatus {
         &self.status
     }
+
+    /// Lines added and removed, when requested through
+    /// `FileComparisonOptions::line_stats`.
+    pub fn lines(&self) -> Option<LineStats> {
+        self.lines
+    }
+
+    /// The diff of the file, when requested through
+    /// `FileComparisonOptions::patch_context`.
+    pub fn patch(&self) -> Option<&FilePatch> {
+        self.patch.as_ref()
+    }
+
+    /// Whether every difference in the file is whitespace ignored through
+    /// `FileComparisonOptions::whitespace`.
+    pub fn is_whitespace_only(&self) -> bool {
+        self.whitespace_only
+    }
+
+    /// Whether `.gitattributes` sets `linguist-generated` or `-diff` for
+    /// the file.
+    pub fn is_generated(&self) -> bool {
+        self.generated
+    }
+
+    /// The old and new commits when the path is a submodule.
+    pub fn submodule(&self) -> Option<&SubmoduleChange> {
+        self.submodule.as_ref()
+    }
 }
 
 impl FileChangeStatus {
@@ -0,0 +1,140 @@
+use crate::domain::value_objects::FilePath;
+
+/// The changes to one file as unified diff hunks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilePatch {
+    old_path: Option<FilePath>,
+    old_mode: Option<u32>,
+    new_mode: Option<u32>,
+    binary: bool,
+    hunks: Vec<Hunk>,
+}
+
+/// A run of changed lines with surrounding context, as in an `@@` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    /// Text after the closing `@@`, usually the enclosing function.
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// The line without its trailing newline.
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+impl FilePatch {
+    pub fn new(hunks: Vec<Hunk>) -> Self {
+        Self {
+            old_path: None,
+            old_mode: None,
+            new_mode: None,
+            binary: false,
+            hunks,
+        }
+    }
+
+    /// A patch for a binary file, which has no hunks.
+    pub fn binary() -> Self {
+        Self {
+            old_path: None,
+            old_mode: None,
+            new_mode: None,
+            binary: true,
+            hunks: Vec::new(),
+        }
+    }
+
+    /// Sets the path before a rename or copy.
+    pub fn with_old_path(mut self, old_path: FilePath) -> Self {
+        self.old_path = Some(old_path);
+        self
+    }
+
+    /// Sets the file modes before and after the change, `None` for the
+    /// missing side of an added or deleted file.
+    pub fn with_modes(mut self, old_mode: Option<u32>, new_mode: Option<u32>) -> Self {
+        self.old_mode = old_mode;
+        self.new_mode = new_mode;
+        self
+    }
+
+    pub fn old_path(&self) -> Option<&FilePath> {
+        self.old_path.as_ref()
+    }
+
+    pub fn old_mode(&self) -> Option<u32> {
+        self.old_mode
+    }
+
+    pub fn new_mode(&self) -> Option<u32> {
+        self.new_mode
+    }
+
+    pub fn is_binary(&self) -> bool {
+        self.binary
+    }
+
+    pub fn hunks(&self) -> &[Hunk] {
+        &self.hunks
+    }
+}
+
+impl DiffLineKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Context => "context",
+            Self::Added => "added",
+            Self::Removed => "removed",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "context" => Some(Self::Context),
+            "added" => Some(Self::Added),
+            "removed" => Some(Self::Removed),
+            _ => None,
+        }
+    }
+
+    /// The character that starts the line in a unified diff.
+    pub fn origin(&self) -> char {
+        match self {
+            Self::Context => ' ',
+            Self::Added => '+',
+            Self::Removed => '-',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_names() {
+        for kind in [
+            DiffLineKind::Context,
+            DiffLineKind::Added,
+            DiffLineKind::Removed,
+        ] {
+            assert_eq!(DiffLineKind::from_name(kind.as_str()), Some(kind));
+        }
+        assert_eq!(DiffLineKind::from_name("moved"), None);
+        assert_eq!(DiffLineKind::Removed.origin(), '-');
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::entities::Commit;
+
+/// An issue and the commits whose messages reference it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueReferences {
+    issue: String,
+    commits: Vec<Commit>,
+}
+
+impl IssueReferences {
+    pub fn new(issue: String) -> Self {
+        Self {
+            issue,
+            commits: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, commit: Commit) {
+        self.commits.push(commit);
+    }
+
+    pub fn issue(&self) -> &str {
+        &self.issue
+    }
+
+    pub fn commits(&self) -> &[Commit] {
+        &self.commits
+    }
+
+    pub fn into_commits(self) -> Vec<Commit> {
+        self.commits
+    }
+}
@@ -1,5 +1,19 @@
+pub mod author_stats;
+pub mod base_branch;
+pub mod changelog;
 pub mod commit;
+pub mod directory_stats;
 pub mod file_change;
+pub mod file_patch;
+pub mod issue_references;
+pub mod submodule_change;
 
+pub use author_stats::AuthorStats;
+pub use base_branch::{BaseBranchCandidates, BaseBranchSource};
+pub use changelog::{Changelog, ChangelogEntry, ChangelogSection};
 pub use commit::Commit;
+pub use directory_stats::DirectoryStats;
 pub use file_change::{FileChange, FileChangeStatus};
+pub use file_patch::{DiffLine, DiffLineKind, FilePatch, Hunk};
+pub use issue_references::IssueReferences;
+pub use submodule_change::SubmoduleChange;
@@ -0,0 +1,70 @@
+use crate::domain::entities::Commit;
+use crate::domain::value_objects::CommitHash;
+
+/// A moved submodule pointer. The old commit is missing for an added
+/// submodule and the new one for a removed submodule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmoduleChange {
+    old_commit: Option<CommitHash>,
+    new_commit: Option<CommitHash>,
+    commits: Option<Vec<Commit>>,
+}
+
+impl SubmoduleChange {
+    pub fn new(old_commit: Option<CommitHash>, new_commit: Option<CommitHash>) -> Self {
+        Self {
+            old_commit,
+            new_commit,
+            commits: None,
+        }
+    }
+
+    /// Sets the submodule commits reachable from the new pointer but not
+    /// from the old one.
+    pub fn with_commits(mut self, commits: Vec<Commit>) -> Self {
+        self.commits = Some(commits);
+        self
+    }
+
+    pub fn old_commit(&self) -> Option<&CommitHash> {
+        self.old_commit.as_ref()
+    }
+
+    pub fn new_commit(&self) -> Option<&CommitHash> {
+        self.new_commit.as_ref()
+    }
+
+    /// The commits between the pointers, when requested through
+    /// `FileComparisonOptions::recurse_submodules` and the submodule is
+    /// checked out with both commits.
+    pub fn commits(&self) -> Option<&[Commit]> {
+        self.commits.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(hex: char) -> CommitHash {
+        CommitHash::new(hex.to_string().repeat(40)).unwrap()
+    }
+
+    #[test]
+    fn test_submodule_change() {
+        let change = SubmoduleChange::new(Some(hash('a')), Some(hash('b')));
+        assert_eq!(change.old_commit(), Some(&hash('a')));
+        assert_eq!(change.new_commit(), Some(&hash('b')));
+        assert!(change.commits().is_none());
+
+        let commit = Commit::new(
+            hash('b'),
+            "Dev".to_string(),
+            "dev@example.com".to_string(),
+            0,
+            "Bump".to_string(),
+        );
+        let change = change.with_commits(vec![commit.clone()]);
+        assert_eq!(change.commits(), Some(&[commit][..]));
+    }
+}
@@ -1,29 +1,97 @@
-use crate::domain::entities::{Commit, FileChange};
-use crate::domain::value_objects::BranchName;
+use crate::domain::entities::{BaseBranchCandidates, Commit, FileChange};
+use crate::domain::value_objects::{
+    BranchName, CommitHash, ComparisonEndpoints, FileComparisonOptions, FilePath, LineStats,
+    SignatureStatus, SignatureVerification,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GitRepositoryError {
     #[error("Repository not found or invalid")]
     RepositoryNotFound,
-    #[error("Branch not found: {branch}")]
-    BranchNotFound { branch: String },
+    #[error("Branch not found: {branch}{}", format_suggestions(.suggestions))]
+    BranchNotFound {
+        branch: String,
+        suggestions: Vec<String>,
+    },
     #[error("Git operation failed: {message}")]
     GitOperationFailed { message: String },
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 }
 
+fn format_suggestions(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" (did you mean {}?)", suggestions.join(", "))
+    }
+}
+
+impl GitRepositoryError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RepositoryNotFound => "repository_not_found",
+            Self::BranchNotFound { .. } => "branch_not_found",
+            Self::GitOperationFailed { .. } => "git_operation_failed",
+            Self::IoError(_) => "io_error",
+        }
+    }
+}
+
 #[cfg_attr(test, mockall::automock)]
 pub trait GitRepository {
-    fn get_commits_from_head(&self) -> Result<Vec<Commit>, GitRepositoryError>;
-
-    fn get_commits_from_branch(
+    /// Commits reachable from `target`, or HEAD when `None`, but not from
+    /// `branch`, newest first, as in `git log branch..target`.
+    fn get_commits_not_in_branch(
         &self,
         branch: &BranchName,
+        target: Option<BranchName>,
     ) -> Result<Vec<Commit>, GitRepositoryError>;
 
+    /// The commits `branch` and `target`, or HEAD when `None`, point at,
+    /// and their merge base.
+    fn resolve_endpoints(
+        &self,
+        branch: &BranchName,
+        target: Option<BranchName>,
+    ) -> Result<ComparisonEndpoints, GitRepositoryError>;
+
     fn get_file_changes_between_branches(
         &self,
         branch: &BranchName,
+        options: &FileComparisonOptions,
+    ) -> Result<Vec<FileChange>, GitRepositoryError>;
+
+    /// Files changed by `commit` relative to its first parent, as in
+    /// `git log --name-status`.
+    fn get_commit_file_changes(
+        &self,
+        commit: &CommitHash,
+        options: &FileComparisonOptions,
     ) -> Result<Vec<FileChange>, GitRepositoryError>;
+
+    /// Lines added and removed by `commit` relative to its parent. Merge
+    /// commits count as no change, as in `git log --numstat`.
+    fn get_commit_line_stats(&self, commit: &CommitHash) -> Result<LineStats, GitRepositoryError>;
+
+    /// Contents of `path` in HEAD's tree, or `None` if there is no such file.
+    fn get_file_at_head(&self, path: &FilePath) -> Result<Option<String>, GitRepositoryError>;
+
+    /// Contents of `path` in the tree of `commit`, or `None` if there is no
+    /// such file.
+    fn get_file_at_commit(
+        &self,
+        commit: &CommitHash,
+        path: &FilePath,
+    ) -> Result<Option<String>, GitRepositoryError>;
+
+    fn get_base_branch_candidates(&self) -> Result<BaseBranchCandidates, GitRepositoryError>;
+
+    /// Checks the signature of `commit` against the keys in
+    /// `verification`. Unsigned commits are `SignatureStatus::Unsigned`.
+    fn verify_commit_signature(
+        &self,
+        commit: &CommitHash,
+        verification: &SignatureVerification,
+    ) -> Result<SignatureStatus, GitRepositoryError>;
 }
@@ -0,0 +1,87 @@
+use crate::domain::entities::{AuthorStats, Commit};
+use crate::domain::value_objects::LineStats;
+use std::collections::HashMap;
+
+pub struct AuthorStatisticsDomainService;
+
+impl AuthorStatisticsDomainService {
+    /// Groups commits by author name and email, most active authors first.
+    pub fn aggregate(
+        commits: impl IntoIterator<Item = (Commit, Option<LineStats>)>,
+    ) -> Vec<AuthorStats> {
+        let mut authors: HashMap<(String, String), AuthorStats> = HashMap::new();
+
+        for (commit, lines) in commits {
+            let key = (commit.author().to_string(), commit.email().to_string());
+            authors
+                .entry(key)
+                .and_modify(|stats| stats.record(&commit, lines))
+                .or_insert_with(|| AuthorStats::new(&commit, lines));
+        }
+
+        let mut authors: Vec<AuthorStats> = authors.into_values().collect();
+        authors.sort_by(|a, b| {
+            b.commit_count()
+                .cmp(&a.commit_count())
+                .then_with(|| a.name().cmp(b.name()))
+                .then_with(|| a.email().cmp(b.email()))
+        });
+        authors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CommitHash;
+
+    fn commit(hash: char, author: &str, timestamp: i64) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            author.to_string(),
+            format!("{}@example.com", author.to_lowercase()),
+            timestamp,
+            "Change".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_aggregate_by_author() {
+        let commits = vec![
+            (commit('a', "Alice", 300), Some(LineStats::new(10, 2))),
+            (commit('b', "Bob", 200), Some(LineStats::new(1, 1))),
+            (commit('c', "Alice", 100), Some(LineStats::new(5, 0))),
+        ];
+
+        let authors = AuthorStatisticsDomainService::aggregate(commits);
+
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[0].name(), "Alice");
+        assert_eq!(authors[0].email(), "alice@example.com");
+        assert_eq!(authors[0].commit_count(), 2);
+        assert_eq!(authors[0].first_timestamp(), 100);
+        assert_eq!(authors[0].last_timestamp(), 300);
+        assert_eq!(authors[0].lines(), Some(LineStats::new(15, 2)));
+        assert_eq!(authors[1].name(), "Bob");
+        assert_eq!(authors[1].commit_count(), 1);
+    }
+
+    #[test]
+    fn test_aggregate_ties_sorted_by_name() {
+        let commits = vec![
+            (commit('a', "Zoe", 1), None),
+            (commit('b', "Adam", 2), None),
+        ];
+
+        let authors = AuthorStatisticsDomainService::aggregate(commits);
+
+        assert_eq!(authors[0].name(), "Adam");
+        assert_eq!(authors[1].name(), "Zoe");
+        assert_eq!(authors[0].lines(), None);
+    }
+
+    #[test]
+    fn test_aggregate_empty() {
+        assert!(AuthorStatisticsDomainService::aggregate(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::domain::entities::{BaseBranchCandidates, BaseBranchSource};
+use crate::domain::value_objects::BranchName;
+
+pub struct BaseBranchDomainService;
+
+impl BaseBranchDomainService {
+    /// Picks the upstream of HEAD, then `origin/HEAD`, then the first
+    /// existing local default branch, then `origin/main` or `origin/master`
+    /// as found in single-branch clones.
+    pub fn select(candidates: BaseBranchCandidates) -> Option<(BranchName, BaseBranchSource)> {
+        candidates
+            .upstream
+            .map(|branch| (branch, BaseBranchSource::Upstream))
+            .or_else(|| {
+                candidates
+                    .remote_default
+                    .map(|branch| (branch, BaseBranchSource::RemoteDefault))
+            })
+            .or_else(|| {
+                candidates
+                    .local_defaults
+                    .into_iter()
+                    .next()
+                    .map(|branch| (branch, BaseBranchSource::LocalDefault))
+            })
+            .or_else(|| {
+                candidates
+                    .remote_defaults
+                    .into_iter()
+                    .next()
+                    .map(|branch| (branch, BaseBranchSource::RemoteBranch))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branch(name: &str) -> BranchName {
+        BranchName::new(name.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_select_prefers_upstream() {
+        let candidates = BaseBranchCandidates {
+            upstream: Some(branch("origin/feature")),
+            remote_default: Some(branch("origin/main")),
+            local_defaults: vec![branch("main")],
+            remote_defaults: vec![branch("origin/main")],
+        };
+
+        let (selected, source) = BaseBranchDomainService::select(candidates).unwrap();
+        assert_eq!(selected.as_str(), "origin/feature");
+        assert_eq!(source, BaseBranchSource::Upstream);
+    }
+
+    #[test]
+    fn test_select_falls_back_to_remote_default() {
+        let candidates = BaseBranchCandidates {
+            upstream: None,
+            remote_default: Some(branch("origin/main")),
+            local_defaults: vec![branch("master")],
+            remote_defaults: vec![branch("origin/master")],
+        };
+
+        let (selected, source) = BaseBranchDomainService::select(candidates).unwrap();
+        assert_eq!(selected.as_str(), "origin/main");
+        assert_eq!(source, BaseBranchSource::RemoteDefault);
+    }
+
+    #[test]
+    fn test_select_falls_back_to_local_default() {
+        let candidates = BaseBranchCandidates {
+            local_defaults: vec![branch("main"), branch("master")],
+            remote_defaults: vec![branch("origin/main")],
+            ..BaseBranchCandidates::default()
+        };
+
+        let (selected, source) = BaseBranchDomainService::select(candidates).unwrap();
+        assert_eq!(selected.as_str(), "main");
+        assert_eq!(source, BaseBranchSource::LocalDefault);
+    }
+
+    #[test]
+    fn test_select_falls_back_to_remote_branch() {
+        let candidates = BaseBranchCandidates {
+            remote_defaults: vec![branch("origin/main"), branch("origin/master")],
+            ..BaseBranchCandidates::default()
+        };
+
+        let (selected, source) = BaseBranchDomainService::select(candidates).unwrap();
+        assert_eq!(selected.as_str(), "origin/main");
+        assert_eq!(source, BaseBranchSource::RemoteBranch);
+    }
+
+    #[test]
+    fn test_select_nothing() {
+        assert!(BaseBranchDomainService::select(BaseBranchCandidates::default()).is_none());
+    }
+}
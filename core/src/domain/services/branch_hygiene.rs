@@ -0,0 +1,154 @@
+use crate::domain::entities::Commit;
+use crate::domain::value_objects::CommitWarning;
+
+pub struct BranchHygieneDomainService;
+
+impl BranchHygieneDomainService {
+    /// Warnings for `commit`, one of the commits in the branch that are not
+    /// in the base branch (`difference`, in topological order with every
+    /// commit before its parents).
+    ///
+    /// A fixup is reported when it names an older commit of the branch by
+    /// subject, subject prefix or hash prefix, as `git rebase --autosquash`
+    /// would. A merge is reported when a parent other than the first is
+    /// not in the difference, which means it is in the base branch.
+    pub fn warnings(commit: &Commit, difference: &[Commit]) -> Vec<CommitWarning> {
+        let mut warnings = Vec::new();
+
+        if let Some((prefix, target)) = commit.autosquash_target() {
+            let older = difference
+                .iter()
+                .skip_while(|candidate| candidate.hash() != commit.hash())
+                .skip(1);
+            if let Some(found) = find_target(older, target) {
+                warnings.push(CommitWarning::UnsquashedFixup {
+                    prefix,
+                    target: found.hash().clone(),
+                });
+            }
+        }
+
+        if commit.is_merge() {
+            for parent in &commit.parents()[1..] {
+                if !difference
+                    .iter()
+                    .any(|candidate| candidate.hash() == parent)
+                {
+                    warnings.push(CommitWarning::MergeFromBase {
+                        parent: parent.clone(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+}
+
+fn find_target<'a>(
+    candidates: impl Iterator<Item = &'a Commit> + Clone,
+    target: &str,
+) -> Option<&'a Commit> {
+    let is_hash_prefix = target.len() >= 4 && target.chars().all(|c| c.is_ascii_hexdigit());
+
+    candidates
+        .clone()
+        .find(|candidate| candidate.message() == target)
+        .or_else(|| {
+            candidates
+                .clone()
+                .find(|candidate| is_hash_prefix && candidate.hash().as_str().starts_with(target))
+        })
+        .or_else(|| {
+            candidates
+                .clone()
+                .find(|candidate| !target.is_empty() && candidate.message().starts_with(target))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CommitHash;
+
+    fn hash(c: char) -> CommitHash {
+        CommitHash::new(c.to_string().repeat(40)).unwrap()
+    }
+
+    fn commit(c: char, summary: &str, parents: &[char]) -> Commit {
+        Commit::new(
+            hash(c),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+        .with_parents(parents.iter().map(|&parent| hash(parent)).collect())
+    }
+
+    #[test]
+    fn test_unsquashed_fixups() {
+        let difference = vec![
+            commit('e', "squash! eeee", &['d']),
+            commit('d', "fixup! Add parser", &['c']),
+            commit('c', "amend! Add lexer", &['b']),
+            commit('b', "Add parser", &['a']),
+        ];
+        let warnings: Vec<Vec<CommitWarning>> = difference
+            .iter()
+            .map(|commit| BranchHygieneDomainService::warnings(commit, &difference))
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                vec![],
+                vec![CommitWarning::UnsquashedFixup {
+                    prefix: "fixup!",
+                    target: hash('b'),
+                }],
+                vec![],
+                vec![],
+            ]
+        );
+        assert_eq!(
+            warnings[1][0].message(),
+            "fixup! commit for bbbbbbb should be squashed before merging"
+        );
+    }
+
+    #[test]
+    fn test_fixup_by_hash_and_prefix() {
+        let difference = vec![
+            commit('d', "fixup! bbbbbbb", &['c']),
+            commit('c', "fixup! Add pars", &['b']),
+            commit('b', "Add parser", &['a']),
+        ];
+
+        for fixup in &difference[..2] {
+            assert_eq!(
+                BranchHygieneDomainService::warnings(fixup, &difference),
+                vec![CommitWarning::UnsquashedFixup {
+                    prefix: "fixup!",
+                    target: hash('b'),
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn test_merges() {
+        let difference = vec![
+            commit('e', "Merge branch 'main' into feature", &['d', '1']),
+            commit('d', "Merge branch 'other' into feature", &['b', 'c']),
+            commit('c', "Add other", &['a']),
+            commit('b', "Add parser", &['a']),
+        ];
+
+        assert_eq!(
+            BranchHygieneDomainService::warnings(&difference[0], &difference),
+            vec![CommitWarning::MergeFromBase { parent: hash('1') }]
+        );
+        assert!(BranchHygieneDomainService::warnings(&difference[1], &difference).is_empty());
+    }
+}
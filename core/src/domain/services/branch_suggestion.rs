@@ -0,0 +1,87 @@
+const MAX_SUGGESTIONS: usize = 5;
+
+pub struct BranchSuggestionDomainService;
+
+impl BranchSuggestionDomainService {
+    /// Ranks `candidates` by edit distance to `query`. Each candidate is also
+    /// compared by its trailing path segments so that `main` matches
+    /// `origin/main` and `feature/x` matches `origin/feature/x`.
+    pub fn suggest(query: &str, candidates: &[String]) -> Vec<String> {
+        let threshold = (query.chars().count() / 3).max(2);
+
+        let mut scored: Vec<(usize, &String)> = candidates
+            .iter()
+            .filter_map(|candidate| {
+                let distance = Self::distance(query, candidate);
+                (distance <= threshold).then_some((distance, candidate))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+        scored.dedup_by(|a, b| a.1 == b.1);
+
+        scored
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, candidate)| candidate.clone())
+            .collect()
+    }
+
+    fn distance(query: &str, candidate: &str) -> usize {
+        candidate
+            .match_indices('/')
+            .map(|(index, _)| &candidate[index + 1..])
+            .chain(std::iter::once(candidate))
+            .map(|name| strsim::osa_distance(query, name))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_suggest_typo() {
+        let result = BranchSuggestionDomainService::suggest(
+            "mian",
+            &candidates(&["main", "develop", "release/1.0"]),
+        );
+
+        assert_eq!(result, vec!["main"]);
+    }
+
+    #[test]
+    fn test_suggest_remote_branch() {
+        let result = BranchSuggestionDomainService::suggest(
+            "main",
+            &candidates(&["origin/main", "origin/HEAD", "upstream/maint"]),
+        );
+
+        assert_eq!(result, vec!["origin/main", "upstream/maint"]);
+    }
+
+    #[test]
+    fn test_suggest_ranks_by_distance() {
+        let result = BranchSuggestionDomainService::suggest(
+            "feature/login",
+            &candidates(&["feature/logout", "origin/feature/login", "feature/log"]),
+        );
+
+        assert_eq!(result[0], "origin/feature/login");
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_suggest_no_match() {
+        let result =
+            BranchSuggestionDomainService::suggest("main", &candidates(&["develop", "v1.2.3"]));
+
+        assert!(result.is_empty());
+    }
+}
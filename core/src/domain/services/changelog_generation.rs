@@ -0,0 +1,140 @@
+use crate::domain::entities::{Changelog, ChangelogEntry, ChangelogSection, Commit};
+
+pub struct ChangelogDomainService;
+
+impl ChangelogDomainService {
+    /// Sorts Conventional Commits into changelog sections, keeping the
+    /// order of `commits`. Breaking commits are listed under Breaking
+    /// Changes, with the `BREAKING CHANGE` footer as their description when
+    /// there is one, as well as under their type. Commits of other types
+    /// and messages that are not Conventional Commits are left out.
+    pub fn build(commits: &[Commit]) -> Changelog {
+        let mut changelog = Changelog::default();
+
+        for commit in commits {
+            let Some(conventional) = commit.conventional() else {
+                continue;
+            };
+            let entry = |description: &str| {
+                ChangelogEntry::new(
+                    commit.hash().clone(),
+                    conventional.scope().map(str::to_string),
+                    description.to_string(),
+                )
+            };
+
+            if conventional.is_breaking() {
+                let description = conventional
+                    .breaking_change()
+                    .unwrap_or(conventional.description());
+                changelog.push(ChangelogSection::BreakingChanges, entry(description));
+            }
+
+            let section = match conventional.commit_type() {
+                "feat" => ChangelogSection::Features,
+                "fix" => ChangelogSection::Fixes,
+                _ => continue,
+            };
+            changelog.push(section, entry(conventional.description()));
+        }
+
+        changelog
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{CommitHash, VersionBump};
+
+    fn commit(hash: char, summary: &str, body: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+        .with_body(body.to_string())
+    }
+
+    fn descriptions(changelog: &Changelog, section: ChangelogSection) -> Vec<&str> {
+        changelog
+            .entries(section)
+            .iter()
+            .map(ChangelogEntry::description)
+            .collect()
+    }
+
+    #[test]
+    fn test_build() {
+        let commits = vec![
+            commit('a', "feat(cli): add changelog", ""),
+            commit('b', "fix: handle empty repositories", ""),
+            commit('c', "chore: bump dependencies", ""),
+            commit('d', "Update README", ""),
+            commit(
+                'e',
+                "refactor!: rename options",
+                "BREAKING CHANGE: `--base` is now positional",
+            ),
+        ];
+
+        let changelog = ChangelogDomainService::build(&commits);
+
+        assert_eq!(
+            descriptions(&changelog, ChangelogSection::BreakingChanges),
+            vec!["`--base` is now positional"]
+        );
+        assert_eq!(
+            descriptions(&changelog, ChangelogSection::Features),
+            vec!["add changelog"]
+        );
+        assert_eq!(
+            changelog.entries(ChangelogSection::Features)[0].scope(),
+            Some("cli")
+        );
+        assert_eq!(
+            descriptions(&changelog, ChangelogSection::Fixes),
+            vec!["handle empty repositories"]
+        );
+        assert_eq!(changelog.bump(), VersionBump::Major);
+    }
+
+    #[test]
+    fn test_bump() {
+        let fixes = vec![commit('a', "fix: typo", "")];
+        assert_eq!(
+            ChangelogDomainService::build(&fixes).bump(),
+            VersionBump::Patch
+        );
+
+        let features = vec![commit('a', "fix: typo", ""), commit('b', "feat: add", "")];
+        assert_eq!(
+            ChangelogDomainService::build(&features).bump(),
+            VersionBump::Minor
+        );
+
+        let chores = vec![commit('a', "chore: tidy", "")];
+        assert_eq!(
+            ChangelogDomainService::build(&chores).bump(),
+            VersionBump::None
+        );
+    }
+
+    #[test]
+    fn test_breaking_feature_is_listed_twice() {
+        let commits = vec![commit('a', "feat(api)!: drop v1 endpoints", "")];
+
+        let changelog = ChangelogDomainService::build(&commits);
+
+        assert_eq!(
+            descriptions(&changelog, ChangelogSection::BreakingChanges),
+            vec!["drop v1 endpoints"]
+        );
+        assert_eq!(
+            descriptions(&changelog, ChangelogSection::Features),
+            vec!["drop v1 endpoints"]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::domain::entities::Commit;
+use crate::domain::value_objects::{LintRule, LintRules, LintViolation};
+
+/// Verbs common in commit subjects, for spotting `Adds` and `Fixes`.
+const COMMON_VERBS: [&str; 40] = [
+    "add",
+    "adjust",
+    "allow",
+    "avoid",
+    "bump",
+    "change",
+    "check",
+    "clean",
+    "convert",
+    "correct",
+    "create",
+    "delete",
+    "disable",
+    "document",
+    "drop",
+    "enable",
+    "ensure",
+    "extract",
+    "fix",
+    "handle",
+    "implement",
+    "improve",
+    "introduce",
+    "make",
+    "merge",
+    "move",
+    "prevent",
+    "reduce",
+    "refactor",
+    "remove",
+    "rename",
+    "replace",
+    "return",
+    "revert",
+    "show",
+    "simplify",
+    "support",
+    "update",
+    "upgrade",
+    "use",
+];
+
+/// Imperative words that happen to end like past tenses or gerunds.
+const IMPERATIVE_EXCEPTIONS: [&str; 14] = [
+    "bring", "embed", "exceed", "feed", "need", "ping", "proceed", "ring", "seed", "shed", "speed",
+    "string", "succeed", "wring",
+];
+
+pub struct CommitLintDomainService;
+
+impl CommitLintDomainService {
+    /// The enabled rules that the summary line of `commit` breaks, in rule
+    /// order.
+    pub fn violations(commit: &Commit, rules: &LintRules) -> Vec<LintViolation> {
+        let subject = commit.message().trim();
+
+        rules
+            .rules()
+            .iter()
+            .filter_map(|&rule| {
+                let message = match rule {
+                    LintRule::SubjectLength => {
+                        let length = subject.chars().count();
+                        (length > rules.max_subject_length()).then(|| {
+                            format!(
+                                "Subject is {} characters long, more than {}",
+                                length,
+                                rules.max_subject_length()
+                            )
+                        })
+                    }
+                    LintRule::TrailingPeriod => (subject.ends_with('.')
+                        && !subject.ends_with(".."))
+                    .then(|| "Subject ends with a period".to_string()),
+                    LintRule::ImperativeMood => non_imperative_word(commit).map(|word| {
+                        format!("Subject should use the imperative mood, not \"{}\"", word)
+                    }),
+                    LintRule::ConventionalCommits => commit.conventional().is_none().then(|| {
+                        "Subject is not a Conventional Commits header (type(scope): description)"
+                            .to_string()
+                    }),
+                    LintRule::NoFixup => commit.autosquash_target().map(|(prefix, _)| {
+                        format!("{} commit should be squashed before merging", prefix)
+                    }),
+                    LintRule::NoWip => {
+                        is_work_in_progress(commit).then(|| "Work-in-progress commit".to_string())
+                    }
+                };
+                message.map(|message| LintViolation::new(rule, message))
+            })
+            .collect()
+    }
+}
+
+/// The first word of the description, when it looks like a past tense
+/// (`Added`), a gerund (`Adding`) or a third-person form (`Adds`).
+fn non_imperative_word(commit: &Commit) -> Option<String> {
+    let description = match commit.conventional() {
+        Some(conventional) => conventional.description().to_string(),
+        None => commit.message().to_string(),
+    };
+    let word = description
+        .split_whitespace()
+        .next()?
+        .trim_matches(|c: char| !c.is_alphanumeric());
+    let lower = word.to_lowercase();
+
+    if IMPERATIVE_EXCEPTIONS.contains(&lower.as_str()) || !lower.chars().all(char::is_alphabetic) {
+        return None;
+    }
+
+    let past_or_gerund = lower.len() > 4 && (lower.ends_with("ed") || lower.ends_with("ing"));
+    let third_person = [
+        lower.strip_suffix("ies").map(|stem| format!("{}y", stem)),
+        lower.strip_suffix("es").map(str::to_string),
+        lower.strip_suffix('s').map(str::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|stem| COMMON_VERBS.contains(&stem.as_str()));
+
+    (past_or_gerund || third_person).then(|| word.to_string())
+}
+
+/// Whether the subject, or the description after a Conventional Commits
+/// `type(scope)!:` prefix, starts with `WIP` or "work in progress".
+fn is_work_in_progress(commit: &Commit) -> bool {
+    starts_with_wip(commit.message().trim())
+        || commit
+            .conventional()
+            .is_some_and(|conventional| starts_with_wip(conventional.description()))
+}
+
+fn starts_with_wip(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    let first_word = lower
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_matches(|c: char| !c.is_alphanumeric());
+    first_word == "wip" || lower.starts_with("work in progress")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CommitHash;
+
+    fn commit(summary: &str) -> Commit {
+        Commit::new(
+            CommitHash::new("a".repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+    }
+
+    fn all_rules() -> LintRules {
+        LintRules::new(
+            Some(
+                LintRule::ALL
+                    .iter()
+                    .map(|rule| rule.as_str().to_string())
+                    .collect(),
+            ),
+            50,
+        )
+        .unwrap()
+    }
+
+    fn broken_rules(summary: &str) -> Vec<LintRule> {
+        CommitLintDomainService::violations(&commit(summary), &all_rules())
+            .iter()
+            .map(LintViolation::rule)
+            .collect()
+    }
+
+    #[test]
+    fn test_clean_subject() {
+        assert!(broken_rules("feat(cli): add lint command").is_empty());
+        assert!(broken_rules("fix: embed the schema version...").is_empty());
+    }
+
+    #[test]
+    fn test_violations() {
+        assert_eq!(
+            broken_rules("Added a lint command that checks every commit on the branch."),
+            vec![
+                LintRule::SubjectLength,
+                LintRule::TrailingPeriod,
+                LintRule::ImperativeMood,
+                LintRule::ConventionalCommits,
+            ]
+        );
+        assert_eq!(
+            broken_rules("fixup! feat: add lint command"),
+            vec![LintRule::ConventionalCommits, LintRule::NoFixup]
+        );
+        assert_eq!(
+            broken_rules("[WIP] feat: lint"),
+            vec![LintRule::ConventionalCommits, LintRule::NoWip]
+        );
+    }
+
+    #[test]
+    fn test_wip_after_conventional_prefix() {
+        assert_eq!(broken_rules("fix: WIP login"), vec![LintRule::NoWip]);
+        assert_eq!(
+            broken_rules("feat(auth)!: [WIP] login"),
+            vec![LintRule::NoWip]
+        );
+        assert_eq!(
+            broken_rules("chore: work in progress"),
+            vec![LintRule::NoWip]
+        );
+        assert!(broken_rules("fix: wipe stale caches").is_empty());
+    }
+
+    #[test]
+    fn test_imperative_mood() {
+        let rules = LintRules::new(Some(vec!["imperative-mood".to_string()]), 72).unwrap();
+        let is_imperative = |summary: &str| {
+            CommitLintDomainService::violations(&commit(summary), &rules).is_empty()
+        };
+
+        for summary in [
+            "fix: handling of tags",
+            "Updates README",
+            "Simplifies parser",
+        ] {
+            assert!(!is_imperative(summary), "{}", summary);
+        }
+        for summary in [
+            "feat: need fewer options",
+            "Address review",
+            "Use v2",
+            "v2.0",
+        ] {
+            assert!(is_imperative(summary), "{}", summary);
+        }
+    }
+
+    #[test]
+    fn test_messages() {
+        let violations =
+            CommitLintDomainService::violations(&commit("Fixed it."), &LintRules::default());
+        let messages: Vec<&str> = violations.iter().map(LintViolation::message).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Subject ends with a period",
+                "Subject should use the imperative mood, not \"Fixed\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::domain::entities::{DirectoryStats, FileChange};
+use std::collections::BTreeMap;
+
+const ROOT_DIRECTORY: &str = "./";
+
+pub struct DirectoryStatisticsDomainService;
+
+impl DirectoryStatisticsDomainService {
+    /// Groups file changes by their first `depth` directory components,
+    /// sorted by directory. Files without line stats count as unchanged lines.
+    pub fn aggregate(file_changes: &[FileChange], depth: usize) -> Vec<DirectoryStats> {
+        let mut directories: BTreeMap<String, DirectoryStats> = BTreeMap::new();
+
+        for file_change in file_changes {
+            let directory = Self::directory_of(file_change.path().as_str(), depth);
+            directories
+                .entry(directory.clone())
+                .or_insert_with(|| DirectoryStats::new(directory))
+                .record(file_change.lines().unwrap_or_default());
+        }
+
+        directories.into_values().collect()
+    }
+
+    fn directory_of(path: &str, depth: usize) -> String {
+        let components: Vec<&str> = path.split('/').collect();
+        let directories = &components[..components.len() - 1];
+        let prefix = &directories[..directories.len().min(depth)];
+
+        if prefix.is_empty() {
+            ROOT_DIRECTORY.to_string()
+        } else {
+            format!("{}/", prefix.join("/"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::FileChangeStatus;
+    use crate::domain::value_objects::{FilePath, LineStats};
+
+    fn change(path: &str, added: usize, removed: usize) -> FileChange {
+        FileChange::new(
+            FilePath::new(path.to_string()).unwrap(),
+            FileChangeStatus::Modified,
+        )
+        .with_lines(LineStats::new(added, removed))
+    }
+
+    #[test]
+    fn test_directory_of() {
+        assert_eq!(
+            DirectoryStatisticsDomainService::directory_of("core/src/lib.rs", 1),
+            "core/"
+        );
+        assert_eq!(
+            DirectoryStatisticsDomainService::directory_of("core/src/lib.rs", 2),
+            "core/src/"
+        );
+        assert_eq!(
+            DirectoryStatisticsDomainService::directory_of("core/Cargo.toml", 2),
+            "core/"
+        );
+        assert_eq!(
+            DirectoryStatisticsDomainService::directory_of("README.md", 2),
+            "./"
+        );
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let changes = vec![
+            change("core/src/lib.rs", 10, 2),
+            change("core/src/main.rs", 3, 0),
+            change("cli/src/main.rs", 1, 1),
+            change("README.md", 4, 4),
+        ];
+
+        let directories = DirectoryStatisticsDomainService::aggregate(&changes, 1);
+
+        let summary: Vec<(&str, usize, usize)> = directories
+            .iter()
+            .map(|stats| (stats.directory(), stats.files(), stats.changed_lines()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("./", 1, 8), ("cli/", 1, 2), ("core/", 2, 15)]
+        );
+    }
+}
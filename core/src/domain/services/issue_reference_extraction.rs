@@ -0,0 +1,126 @@
+use crate::domain::entities::{Commit, IssueReferences};
+use crate::domain::value_objects::{Footer, IssuePatterns};
+use std::collections::HashMap;
+
+/// Footer tokens whose values name issues even when no pattern matches
+/// them, compared case-insensitively.
+const ISSUE_TRAILERS: [&str; 6] = ["Fixes", "Closes", "Resolves", "Refs", "Ref", "Issue"];
+
+pub struct IssueReferenceDomainService;
+
+impl IssueReferenceDomainService {
+    /// Issues referenced in the summary, body and issue trailers of
+    /// `commit`, in order of first appearance.
+    pub fn references(commit: &Commit, patterns: &IssuePatterns) -> Vec<String> {
+        let mut issues = patterns.find_all(commit.message());
+        let mut add = |issue: String| {
+            if !issues.contains(&issue) {
+                issues.push(issue);
+            }
+        };
+
+        patterns
+            .find_all(commit.body())
+            .into_iter()
+            .for_each(&mut add);
+
+        for footer in Footer::parse_all(commit.body()) {
+            let is_issue_trailer = ISSUE_TRAILERS
+                .iter()
+                .any(|token| token.eq_ignore_ascii_case(footer.token()));
+            if !is_issue_trailer || !patterns.find_all(footer.value()).is_empty() {
+                continue;
+            }
+            footer
+                .value()
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .for_each(|value| add(value.to_string()));
+        }
+
+        issues
+    }
+
+    /// Groups `commits` by the issues they reference, ordered by issue key
+    /// with numbers compared numerically. Commits keep their given order.
+    pub fn group(commits: Vec<Commit>, patterns: &IssuePatterns) -> Vec<IssueReferences> {
+        let mut issues: HashMap<String, IssueReferences> = HashMap::new();
+
+        for commit in commits {
+            for issue in Self::references(&commit, patterns) {
+                issues
+                    .entry(issue.clone())
+                    .or_insert_with(|| IssueReferences::new(issue))
+                    .record(commit.clone());
+            }
+        }
+
+        let mut issues: Vec<IssueReferences> = issues.into_values().collect();
+        issues.sort_by(|a, b| issue_sort_key(a.issue()).cmp(&issue_sort_key(b.issue())));
+        issues
+    }
+}
+
+/// `PROJ-12` sorts as (`PROJ-`, 12, ``) so that `PROJ-9` comes before
+/// `PROJ-10`.
+fn issue_sort_key(issue: &str) -> (&str, Option<u64>, &str) {
+    let prefix_end = issue
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(issue.len());
+    let (prefix, rest) = issue.split_at(prefix_end);
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, suffix) = rest.split_at(digits_end);
+    (prefix, digits.parse().ok(), suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::CommitHash;
+
+    fn commit(hash: char, summary: &str, body: &str) -> Commit {
+        Commit::new(
+            CommitHash::new(hash.to_string().repeat(40)).unwrap(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            1634567890,
+            summary.to_string(),
+        )
+        .with_body(body.to_string())
+    }
+
+    #[test]
+    fn test_references() {
+        let commit = commit(
+            'a',
+            "PROJ-12: fix login (#34)",
+            "Also touches PROJ-12.\n\nFixes: #35\nCloses: legacy-ticket, other-ticket\nReviewed-by: Bob",
+        );
+
+        assert_eq!(
+            IssueReferenceDomainService::references(&commit, &IssuePatterns::default()),
+            vec!["PROJ-12", "#34", "#35", "legacy-ticket", "other-ticket"]
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let commits = vec![
+            commit('a', "Fix PROJ-10", ""),
+            commit('b', "Fix PROJ-9 and PROJ-10", ""),
+            commit('c', "Update README", ""),
+        ];
+
+        let issues = IssueReferenceDomainService::group(commits, &IssuePatterns::default());
+
+        let summary: Vec<(&str, usize)> = issues
+            .iter()
+            .map(|issue| (issue.issue(), issue.commits().len()))
+            .collect();
+        assert_eq!(summary, vec![("PROJ-9", 1), ("PROJ-10", 2)]);
+        assert_eq!(issues[1].commits()[0].message(), "Fix PROJ-10");
+    }
+}
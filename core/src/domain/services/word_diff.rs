@@ -0,0 +1,165 @@
+use crate::domain::entities::DiffLineKind;
+
+pub struct WordDiffDomainService;
+
+impl WordDiffDomainService {
+    /// Renders the lines of a hunk as `git diff --word-diff=plain` does:
+    /// removed and added lines that replace each other are merged, with
+    /// removed words in `[-…-]` and added words in `{+…+}`.
+    pub fn render<'a>(
+        hunk_lines: impl IntoIterator<Item = (DiffLineKind, &'a str)>,
+    ) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut removed: Vec<&str> = Vec::new();
+        let mut added: Vec<&str> = Vec::new();
+
+        for (kind, content) in hunk_lines {
+            match kind {
+                DiffLineKind::Removed if added.is_empty() => removed.push(content),
+                DiffLineKind::Added => added.push(content),
+                _ => {
+                    Self::flush(&mut lines, &mut removed, &mut added);
+                    match kind {
+                        DiffLineKind::Removed => removed.push(content),
+                        _ => lines.push(content.to_string()),
+                    }
+                }
+            }
+        }
+        Self::flush(&mut lines, &mut removed, &mut added);
+
+        lines
+    }
+
+    fn flush(lines: &mut Vec<String>, removed: &mut Vec<&str>, added: &mut Vec<&str>) {
+        if removed.is_empty() && added.is_empty() {
+            return;
+        }
+
+        let old = removed.join("\n");
+        let new = added.join("\n");
+        let merged = Self::diff_words(&tokenize(&old), &tokenize(&new));
+        lines.extend(merged.split('\n').map(str::to_string));
+
+        removed.clear();
+        added.clear();
+    }
+
+    /// Merges two token lists through their longest common subsequence.
+    fn diff_words(old: &[&str], new: &[&str]) -> String {
+        // lengths[i][j] is the LCS length of old[i..] and new[j..].
+        let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                lengths[i][j] = if old[i] == new[j] {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+
+        let mut output = String::new();
+        let mut deleted = String::new();
+        let mut inserted = String::new();
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() || j < new.len() {
+            if i < old.len() && j < new.len() && old[i] == new[j] {
+                push_change(&mut output, &mut deleted, &mut inserted);
+                output.push_str(old[i]);
+                i += 1;
+                j += 1;
+            } else if j < new.len() && (i == old.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+                inserted.push_str(new[j]);
+                j += 1;
+            } else {
+                deleted.push_str(old[i]);
+                i += 1;
+            }
+        }
+        push_change(&mut output, &mut deleted, &mut inserted);
+
+        output
+    }
+}
+
+fn push_change(output: &mut String, deleted: &mut String, inserted: &mut String) {
+    push_marked(output, deleted, "[-", "-]");
+    push_marked(output, inserted, "{+", "+}");
+}
+
+/// Appends `text` between markers, closing and reopening them at line
+/// breaks so that every line stands on its own.
+fn push_marked(output: &mut String, text: &mut String, open: &str, close: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    let marked: Vec<String> = text
+        .split('\n')
+        .map(|part| {
+            if part.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}{}", open, part, close)
+            }
+        })
+        .collect();
+    output.push_str(&marked.join("\n"));
+    text.clear();
+}
+
+/// Splits text into alternating runs of whitespace and non-whitespace.
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous: Option<bool> = None;
+
+    for (index, c) in text.char_indices() {
+        let whitespace = c.is_whitespace();
+        if previous.is_some_and(|previous| previous != whitespace) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        previous = Some(whitespace);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let lines = [
+            (DiffLineKind::Context, "fn main() {"),
+            (DiffLineKind::Removed, "    println!(\"hello world\");"),
+            (DiffLineKind::Added, "    println!(\"hello arlon\");"),
+            (DiffLineKind::Added, "    exit(0);"),
+            (DiffLineKind::Context, "}"),
+            (DiffLineKind::Removed, "// old"),
+        ];
+
+        assert_eq!(
+            WordDiffDomainService::render(lines),
+            vec![
+                "fn main() {",
+                "    println!(\"hello [-world\");-]{+arlon\");+}",
+                "{+    exit(0);+}",
+                "}",
+                "[-// old-]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("a  bc d"), vec!["a", "  ", "bc", " ", "d"]);
+        assert_eq!(tokenize(""), Vec::<&str>::new());
+    }
+}
@@ -7,23 +7,96 @@ pub struct BranchName(String);
 pub enum BranchNameError {
     #[error("Branch name cannot be empty")]
     Empty,
-    #[error("Branch name contains invalid characters: {0}")]
-    InvalidCharacters(String),
+    #[error("Branch name cannot be '@'")]
+    SingleAt,
+    #[error("Branch name cannot start with '-': {0}")]
+    StartsWithDash(String),
+    #[error("Branch name contains forbidden character {character:?}: {name}")]
+    ForbiddenCharacter { name: String, character: char },
+    #[error("Branch name cannot contain '..': {0}")]
+    DoubleDot(String),
+    #[error("Branch name cannot contain '@{{': {0}")]
+    AtBrace(String),
+    #[error("Branch name cannot start or end with '/' or contain '//': {0}")]
+    InvalidSlash(String),
+    #[error("Branch name components cannot start with '.': {0}")]
+    ComponentStartsWithDot(String),
+    #[error("Branch name components cannot end with '.lock': {0}")]
+    ComponentEndsWithLock(String),
+    #[error("Branch name cannot end with '.': {0}")]
+    EndsWithDot(String),
+}
+
+impl BranchNameError {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Empty => None,
+            Self::SingleAt => Some("@"),
+            Self::ForbiddenCharacter { name, .. } => Some(name),
+            Self::StartsWithDash(name)
+            | Self::DoubleDot(name)
+            | Self::AtBrace(name)
+            | Self::InvalidSlash(name)
+            | Self::ComponentStartsWithDot(name)
+            | Self::ComponentEndsWithLock(name)
+            | Self::EndsWithDot(name) => Some(name),
+        }
+    }
 }
 
 impl BranchName {
+    /// Validates `name` against the rules of `git check-ref-format --branch`.
     pub fn new(name: String) -> Result<Self, BranchNameError> {
         if name.is_empty() {
             return Err(BranchNameError::Empty);
         }
 
-        if name.contains("..") || name.starts_with('.') || name.ends_with('.') {
-            return Err(BranchNameError::InvalidCharacters(name));
+        if name == "@" {
+            return Err(BranchNameError::SingleAt);
+        }
+
+        if name.starts_with('-') {
+            return Err(BranchNameError::StartsWithDash(name));
+        }
+
+        if let Some(character) = name.chars().find(|&c| Self::is_forbidden(c)) {
+            return Err(BranchNameError::ForbiddenCharacter { name, character });
+        }
+
+        if name.contains("..") {
+            return Err(BranchNameError::DoubleDot(name));
+        }
+
+        if name.contains("@{") {
+            return Err(BranchNameError::AtBrace(name));
+        }
+
+        if name.starts_with('/') || name.ends_with('/') || name.contains("//") {
+            return Err(BranchNameError::InvalidSlash(name));
+        }
+
+        if name.split('/').any(|component| component.starts_with('.')) {
+            return Err(BranchNameError::ComponentStartsWithDot(name));
+        }
+
+        if name
+            .split('/')
+            .any(|component| component.ends_with(".lock"))
+        {
+            return Err(BranchNameError::ComponentEndsWithLock(name));
+        }
+
+        if name.ends_with('.') {
+            return Err(BranchNameError::EndsWithDot(name));
         }
 
         Ok(Self(name))
     }
 
+    fn is_forbidden(c: char) -> bool {
+        c.is_ascii_control() || matches!(c, ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\')
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -70,10 +143,7 @@ mod tests {
     fn test_branch_name_with_double_dot() {
         let result = BranchName::new("feature..bad".to_string());
         assert!(result.is_err());
-        assert!(matches!(
-            result.unwrap_err(),
-            BranchNameError::InvalidCharacters(_)
-        ));
+        assert!(matches!(result.unwrap_err(), BranchNameError::DoubleDot(_)));
     }
 
     #[test]
@@ -82,7 +152,7 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            BranchNameError::InvalidCharacters(_)
+            BranchNameError::ComponentStartsWithDot(_)
         ));
     }
 
@@ -92,10 +162,114 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            BranchNameError::InvalidCharacters(_)
+            BranchNameError::EndsWithDot(_)
+        ));
+    }
+
+    #[test]
+    fn test_branch_name_with_forbidden_characters() {
+        for name in [
+            "has space",
+            "tilde~1",
+            "caret^",
+            "colon:x",
+            "what?",
+            "star*",
+            "bracket[",
+            "back\\slash",
+            "tab\tname",
+            "del\x7fname",
+        ] {
+            let result = BranchName::new(name.to_string());
+            assert!(
+                matches!(result, Err(BranchNameError::ForbiddenCharacter { .. })),
+                "expected {:?} to be rejected",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_branch_name_forbidden_character_reported() {
+        let result = BranchName::new("topic~2".to_string());
+        match result.unwrap_err() {
+            BranchNameError::ForbiddenCharacter { name, character } => {
+                assert_eq!(name, "topic~2");
+                assert_eq!(character, '~');
+            }
+            e => panic!("Expected ForbiddenCharacter, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_branch_name_with_at_brace() {
+        let result = BranchName::new("main@{1}".to_string());
+        assert!(matches!(result.unwrap_err(), BranchNameError::AtBrace(_)));
+    }
+
+    #[test]
+    fn test_branch_name_single_at() {
+        let result = BranchName::new("@".to_string());
+        assert!(matches!(result.unwrap_err(), BranchNameError::SingleAt));
+    }
+
+    #[test]
+    fn test_branch_name_with_invalid_slashes() {
+        for name in ["/leading", "trailing/", "double//slash"] {
+            let result = BranchName::new(name.to_string());
+            assert!(matches!(result, Err(BranchNameError::InvalidSlash(_))));
+        }
+    }
+
+    #[test]
+    fn test_branch_name_component_starting_with_dot() {
+        let result = BranchName::new("feature/.hidden".to_string());
+        assert!(matches!(
+            result.unwrap_err(),
+            BranchNameError::ComponentStartsWithDot(_)
         ));
     }
 
+    #[test]
+    fn test_branch_name_ending_with_lock() {
+        for name in ["main.lock", "feature.lock/x"] {
+            let result = BranchName::new(name.to_string());
+            assert!(matches!(
+                result,
+                Err(BranchNameError::ComponentEndsWithLock(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_branch_name_starting_with_dash() {
+        let result = BranchName::new("-main".to_string());
+        assert!(matches!(
+            result.unwrap_err(),
+            BranchNameError::StartsWithDash(_)
+        ));
+    }
+
+    #[test]
+    fn test_valid_branch_names() {
+        for name in [
+            "origin/main",
+            "release/v1.2.3",
+            "user@host",
+            "a.b/c-d_e",
+            "日本語",
+        ] {
+            assert!(BranchName::new(name.to_string()).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_branch_name_error_name() {
+        let error = BranchName::new("bad name".to_string()).unwrap_err();
+        assert_eq!(error.name(), Some("bad name"));
+        assert_eq!(BranchNameError::Empty.name(), None);
+    }
+
     #[test]
     fn test_branch_name_display() {
         let branch_name = BranchName::new("main".to_string()).unwrap();
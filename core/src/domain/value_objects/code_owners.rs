@@ -0,0 +1,249 @@
+use crate::domain::value_objects::FilePath;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Ownership rules from a GitHub or GitLab `CODEOWNERS` file.
+///
+/// Within a section the last matching rule wins. GitLab `[Section]`
+/// headers start a new section, and a file's owners are the union of the
+/// matches from every section.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Section {
+    default_owners: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    matcher: GlobSet,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Locations searched for the file, in order of precedence.
+    pub const LOCATIONS: [&'static str; 4] = [
+        ".github/CODEOWNERS",
+        "CODEOWNERS",
+        "docs/CODEOWNERS",
+        ".gitlab/CODEOWNERS",
+    ];
+
+    /// Parses `contents`, skipping lines that are not valid rules as
+    /// GitHub does.
+    pub fn parse(contents: &str) -> Self {
+        let mut sections = vec![Section::default()];
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(default_owners) = parse_section_header(line) {
+                sections.push(Section {
+                    default_owners,
+                    rules: Vec::new(),
+                });
+                continue;
+            }
+
+            let mut tokens = tokenize(line).into_iter();
+            let Some(pattern) = tokens.next() else {
+                continue;
+            };
+            let Some(matcher) = build_matcher(&pattern) else {
+                continue;
+            };
+            let owners = tokens.take_while(|token| !token.starts_with('#')).collect();
+
+            if let Some(section) = sections.last_mut() {
+                section.rules.push(Rule { matcher, owners });
+            }
+        }
+
+        Self { sections }
+    }
+
+    pub fn owners_of(&self, path: &FilePath) -> Vec<String> {
+        let mut owners: Vec<String> = Vec::new();
+
+        for section in &self.sections {
+            let Some(rule) = section
+                .rules
+                .iter()
+                .rev()
+                .find(|rule| rule.matcher.is_match(path.as_str()))
+            else {
+                continue;
+            };
+
+            let rule_owners = if rule.owners.is_empty() {
+                &section.default_owners
+            } else {
+                &rule.owners
+            };
+            for owner in rule_owners {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+
+        owners
+    }
+}
+
+/// Default owners of a GitLab section header such as `[Docs][2] @docs`, or
+/// `None` if `line` is not a header.
+fn parse_section_header(line: &str) -> Option<Vec<String>> {
+    let header = line.strip_prefix('^').unwrap_or(line);
+    let rest = header.strip_prefix('[')?;
+    let (_name, rest) = rest.split_once(']')?;
+
+    let rest = match rest.strip_prefix('[') {
+        Some(approvals) => approvals.split_once(']')?.1,
+        None => rest,
+    };
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(tokenize(rest))
+}
+
+/// Splits on unescaped whitespace, resolving `\` escapes.
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => current.extend(chars.next()),
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// Translates a gitignore-style pattern: a leading or inner `/` anchors it
+/// to the repository root, a trailing `/` matches only directories, and a
+/// pattern naming a directory also matches everything below it.
+fn build_matcher(pattern: &str) -> Option<GlobSet> {
+    let directory_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let body = trimmed.trim_start_matches('/');
+    if body.is_empty() {
+        return None;
+    }
+
+    let anchored = trimmed.starts_with('/') || body.contains('/');
+    let base = if anchored {
+        body.to_string()
+    } else {
+        format!("**/{}", body)
+    };
+
+    let mut globs = Vec::new();
+    if !body.ends_with("/*") && body != "*" {
+        globs.push(format!("{}/**", base));
+    }
+    if !directory_only {
+        globs.push(base);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for glob in globs {
+        let glob = GlobBuilder::new(&glob)
+            .literal_separator(true)
+            .build()
+            .ok()?;
+        builder.add(glob);
+    }
+    builder.build().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owners(code_owners: &CodeOwners, path: &str) -> Vec<String> {
+        code_owners.owners_of(&FilePath::new(path.to_string()).unwrap())
+    }
+
+    #[test]
+    fn test_github_last_match_wins() {
+        let code_owners = CodeOwners::parse(
+            "# Default owners\n\
+             *       @global-owner\n\
+             *.js    @js-owner @org/frontend # inline comment\n\
+             /docs/  docs@example.com\n\
+             apps/   @apps-owner\n\
+             /build/logs/\n",
+        );
+
+        assert_eq!(owners(&code_owners, "README.md"), ["@global-owner"]);
+        assert_eq!(
+            owners(&code_owners, "src/app.js"),
+            ["@js-owner", "@org/frontend"]
+        );
+        assert_eq!(owners(&code_owners, "docs/guide.md"), ["docs@example.com"]);
+        assert_eq!(owners(&code_owners, "src/docs/guide.md"), ["@global-owner"]);
+        assert_eq!(owners(&code_owners, "web/apps/main.rs"), ["@apps-owner"]);
+        assert!(owners(&code_owners, "build/logs/out.txt").is_empty());
+    }
+
+    #[test]
+    fn test_direct_children_only() {
+        let code_owners = CodeOwners::parse("docs/* @docs\n");
+
+        assert_eq!(owners(&code_owners, "docs/index.md"), ["@docs"]);
+        assert!(owners(&code_owners, "docs/api/index.md").is_empty());
+    }
+
+    #[test]
+    fn test_gitlab_sections() {
+        let code_owners = CodeOwners::parse(
+            "* @everyone\n\
+             [Docs][2] @docs-team\n\
+             *.md\n\
+             ^[Backend]\n\
+             /core/ @backend\n\
+             /core/README.md @backend @docs-team\n",
+        );
+
+        assert_eq!(
+            owners(&code_owners, "guide.md"),
+            ["@everyone", "@docs-team"]
+        );
+        assert_eq!(
+            owners(&code_owners, "core/README.md"),
+            ["@everyone", "@docs-team", "@backend"]
+        );
+        assert_eq!(
+            owners(&code_owners, "core/lib.rs"),
+            ["@everyone", "@backend"]
+        );
+    }
+
+    #[test]
+    fn test_escaped_spaces_and_invalid_lines() {
+        let code_owners = CodeOwners::parse("my\\ file.txt @owner\n[unclosed @x\n");
+
+        assert_eq!(owners(&code_owners, "my file.txt"), ["@owner"]);
+        assert!(owners(&code_owners, "other.txt").is_empty());
+    }
+}
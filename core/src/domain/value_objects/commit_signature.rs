@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+/// The kind of signature in a commit's `gpgsig` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureFormat {
+    Gpg,
+    Ssh,
+    X509,
+}
+
+impl SignatureFormat {
+    /// Recognizes the armor of a signature block, as git does.
+    pub fn detect(signature: &str) -> Option<SignatureFormat> {
+        let signature = signature.trim_start();
+        if signature.starts_with("-----BEGIN PGP SIGNATURE-----")
+            || signature.starts_with("-----BEGIN PGP MESSAGE-----")
+        {
+            Some(Self::Gpg)
+        } else if signature.starts_with("-----BEGIN SSH SIGNATURE-----") {
+            Some(Self::Ssh)
+        } else if signature.starts_with("-----BEGIN SIGNED MESSAGE-----") {
+            Some(Self::X509)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gpg => "gpg",
+            Self::Ssh => "ssh",
+            Self::X509 => "x509",
+        }
+    }
+}
+
+/// Whether a commit is signed and, once verified, whether the signature
+/// holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStatus {
+    Unsigned,
+    /// Signed, but the signature has not been verified.
+    Signed,
+    /// A valid signature by a trusted key.
+    Good,
+    /// A signature that does not match the commit, or by an expired or
+    /// revoked key.
+    Bad,
+    /// A signature whose key is not in the keyring or allowed signers, or
+    /// is not trusted.
+    UnknownKey,
+}
+
+impl SignatureStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unsigned => "unsigned",
+            Self::Signed => "signed",
+            Self::Good => "good",
+            Self::Bad => "bad",
+            Self::UnknownKey => "unknown_key",
+        }
+    }
+}
+
+/// Where to find the keys that signatures are verified against. GPG
+/// signatures use the default keyring unless `gpg_keyring` is set; SSH
+/// signatures need an `allowed_signers` file to be trusted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignatureVerification {
+    pub gpg_keyring: Option<PathBuf>,
+    pub allowed_signers: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            SignatureFormat::detect("-----BEGIN PGP SIGNATURE-----\n\niQ..."),
+            Some(SignatureFormat::Gpg)
+        );
+        assert_eq!(
+            SignatureFormat::detect("-----BEGIN SSH SIGNATURE-----\nU1NI..."),
+            Some(SignatureFormat::Ssh)
+        );
+        assert_eq!(
+            SignatureFormat::detect("-----BEGIN SIGNED MESSAGE-----\nMII..."),
+            Some(SignatureFormat::X509)
+        );
+        assert_eq!(SignatureFormat::detect("not a signature"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::domain::value_objects::CommitHash;
+
+/// Something about a commit in the branch that should be cleaned up before
+/// merging.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitWarning {
+    /// A `fixup!`, `squash!` or `amend!` commit whose target is in the
+    /// branch too, so that it can still be autosquashed.
+    UnsquashedFixup {
+        prefix: &'static str,
+        target: CommitHash,
+    },
+    /// A merge commit that brings the base branch into the branch.
+    MergeFromBase { parent: CommitHash },
+}
+
+impl CommitWarning {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnsquashedFixup { .. } => "unsquashed_fixup",
+            Self::MergeFromBase { .. } => "merge_from_base",
+        }
+    }
+
+    /// The fixup target or the merged base commit.
+    pub fn related_commit(&self) -> &CommitHash {
+        match self {
+            Self::UnsquashedFixup { target, .. } => target,
+            Self::MergeFromBase { parent } => parent,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Self::UnsquashedFixup { prefix, target } => format!(
+                "{} commit for {} should be squashed before merging",
+                prefix,
+                target.short()
+            ),
+            Self::MergeFromBase { parent } => format!(
+                "Merges {} from the base branch; consider rebasing instead",
+                parent.short()
+            ),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::domain::value_objects::CommitHash;
+
+/// The commits a comparison was made between, as resolved from the names
+/// given for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonEndpoints {
+    pub base: CommitHash,
+    pub target: CommitHash,
+    /// The best common ancestor, or `None` for unrelated histories.
+    pub merge_base: Option<CommitHash>,
+}
@@ -0,0 +1,251 @@
+/// A commit message that follows the Conventional Commits specification:
+/// `type(scope)!: description`, an optional body and trailing footers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    commit_type: String,
+    scope: Option<String>,
+    breaking: bool,
+    description: String,
+    footers: Vec<Footer>,
+}
+
+/// A `Token: value` or `Token #value` line from the footer of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footer {
+    token: String,
+    value: String,
+}
+
+impl ConventionalCommit {
+    /// Parses the summary line and body of a commit message. Returns `None`
+    /// when the summary is not a Conventional Commits header.
+    pub fn parse(summary: &str, body: &str) -> Option<Self> {
+        let (commit_type, scope, bang, description) = parse_header(summary.trim())?;
+        let footers = Footer::parse_all(body);
+        let breaking = bang || footers.iter().any(Footer::is_breaking_change);
+
+        Some(Self {
+            commit_type,
+            scope,
+            breaking,
+            description,
+            footers,
+        })
+    }
+
+    /// The type, lowercased (`feat`, `fix`, ...).
+    pub fn commit_type(&self) -> &str {
+        &self.commit_type
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Whether the header has a `!` or a footer is a `BREAKING CHANGE`.
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn footers(&self) -> &[Footer] {
+        &self.footers
+    }
+
+    /// The text of the `BREAKING CHANGE` footer, if there is one.
+    pub fn breaking_change(&self) -> Option<&str> {
+        self.footers
+            .iter()
+            .find(|footer| footer.is_breaking_change())
+            .map(Footer::value)
+    }
+}
+
+impl Footer {
+    /// Parses the footers in the last paragraph of `body`. Lines that do not
+    /// start a footer continue the value of the previous one.
+    pub fn parse_all(body: &str) -> Vec<Footer> {
+        let body = body.replace("\r\n", "\n");
+        let Some(paragraph) = body
+            .trim()
+            .rsplit("\n\n")
+            .map(str::trim)
+            .find(|paragraph| !paragraph.is_empty())
+        else {
+            return Vec::new();
+        };
+
+        let mut lines = paragraph.lines();
+        let Some(first) = lines.next().and_then(Footer::parse_line) else {
+            return Vec::new();
+        };
+
+        let mut footers = vec![first];
+        for line in lines {
+            match Footer::parse_line(line) {
+                Some(footer) => footers.push(footer),
+                None => {
+                    if let Some(footer) = footers.last_mut() {
+                        footer.value.push('\n');
+                        footer.value.push_str(line);
+                    }
+                }
+            }
+        }
+        footers
+    }
+
+    fn parse_line(line: &str) -> Option<Footer> {
+        if let Some((token, value)) = line.split_once(": ") {
+            if is_footer_token(token) {
+                return Some(Footer {
+                    token: token.to_string(),
+                    value: value.trim().to_string(),
+                });
+            }
+        }
+
+        let (token, value) = line.split_once(" #")?;
+        is_footer_token(token).then(|| Footer {
+            token: token.to_string(),
+            value: format!("#{}", value.trim()),
+        })
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_breaking_change(&self) -> bool {
+        self.token == "BREAKING CHANGE" || self.token == "BREAKING-CHANGE"
+    }
+}
+
+fn is_footer_token(token: &str) -> bool {
+    token == "BREAKING CHANGE"
+        || (!token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
+fn parse_header(header: &str) -> Option<(String, Option<String>, bool, String)> {
+    let (prefix, description) = header.split_once(": ")?;
+    let description = description.trim();
+    if description.is_empty() {
+        return None;
+    }
+
+    let (prefix, bang) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((commit_type, rest)) => {
+            let scope = rest.strip_suffix(')')?;
+            if scope.is_empty() || scope.contains(['(', ')']) {
+                return None;
+            }
+            (commit_type, Some(scope.to_string()))
+        }
+        None => (prefix, None),
+    };
+
+    if commit_type.is_empty()
+        || !commit_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return None;
+    }
+
+    Some((
+        commit_type.to_ascii_lowercase(),
+        scope,
+        bang,
+        description.to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header() {
+        let commit = ConventionalCommit::parse("feat(cli): add changelog command", "").unwrap();
+
+        assert_eq!(commit.commit_type(), "feat");
+        assert_eq!(commit.scope(), Some("cli"));
+        assert!(!commit.is_breaking());
+        assert_eq!(commit.description(), "add changelog command");
+        assert!(commit.footers().is_empty());
+    }
+
+    #[test]
+    fn test_parse_breaking_bang() {
+        let commit = ConventionalCommit::parse("Fix!: drop support for git 1.x", "").unwrap();
+
+        assert_eq!(commit.commit_type(), "fix");
+        assert_eq!(commit.scope(), None);
+        assert!(commit.is_breaking());
+        assert_eq!(commit.breaking_change(), None);
+    }
+
+    #[test]
+    fn test_parse_footers() {
+        let body = "Explain the change.\n\nBREAKING CHANGE: the `--base` flag is gone\n  use the positional argument instead\nRefs #42\nReviewed-by: Alice";
+        let commit = ConventionalCommit::parse("refactor: simplify options", body).unwrap();
+
+        assert!(commit.is_breaking());
+        assert_eq!(
+            commit.breaking_change(),
+            Some("the `--base` flag is gone\n  use the positional argument instead")
+        );
+        let footers: Vec<(&str, &str)> = commit
+            .footers()
+            .iter()
+            .map(|footer| (footer.token(), footer.value()))
+            .collect();
+        assert_eq!(
+            footers,
+            vec![
+                (
+                    "BREAKING CHANGE",
+                    "the `--base` flag is gone\n  use the positional argument instead"
+                ),
+                ("Refs", "#42"),
+                ("Reviewed-by", "Alice"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_body_without_footers() {
+        let commit =
+            ConventionalCommit::parse("docs: update README", "Just some prose: nothing else.")
+                .unwrap();
+
+        assert!(commit.footers().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_other_messages() {
+        for summary in [
+            "Update README",
+            "feat:missing space",
+            "feat: ",
+            "feat(): empty scope",
+            "feat(cli: unclosed scope",
+            "two words: not a type",
+            "Merge branch 'main' into feature",
+        ] {
+            assert_eq!(ConventionalCommit::parse(summary, ""), None, "{}", summary);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormat(String);
+
+#[derive(Debug, thiserror::Error)]
+pub enum DateFormatError {
+    #[error("Date format cannot be empty")]
+    Empty,
+    #[error("Invalid date format: {0}")]
+    InvalidFormat(String),
+}
+
+impl DateFormat {
+    pub const DEFAULT: &'static str = "%Y-%m-%d %H:%M:%S";
+
+    pub fn new(format: String) -> Result<Self, DateFormatError> {
+        if format.is_empty() {
+            return Err(DateFormatError::Empty);
+        }
+
+        if StrftimeItems::new(&format).any(|item| matches!(item, Item::Error)) {
+            return Err(DateFormatError::InvalidFormat(format));
+        }
+
+        Ok(Self(format))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Formats a Unix timestamp in the timezone `offset_minutes` east of
+    /// UTC, as git shows a commit's date in the committer's timezone.
+    pub fn format_timestamp(&self, timestamp: i64, offset_minutes: i32) -> String {
+        let offset = FixedOffset::east_opt(offset_minutes * 60)
+            .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        let datetime = DateTime::from_timestamp(timestamp, 0).unwrap_or_default();
+        datetime.with_timezone(&offset).format(&self.0).to_string()
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self(Self::DEFAULT.to_string())
+    }
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_date_format() {
+        let date_format = DateFormat::new("%Y-%m-%d".to_string());
+        assert!(date_format.is_ok());
+        assert_eq!(date_format.unwrap().as_str(), "%Y-%m-%d");
+    }
+
+    #[test]
+    fn test_empty_date_format() {
+        let result = DateFormat::new("".to_string());
+        assert!(matches!(result.unwrap_err(), DateFormatError::Empty));
+    }
+
+    #[test]
+    fn test_invalid_date_format() {
+        let result = DateFormat::new("%Y-%Q".to_string());
+        assert!(matches!(
+            result.unwrap_err(),
+            DateFormatError::InvalidFormat(_)
+        ));
+    }
+
+    #[test]
+    fn test_format_timestamp_in_offset() {
+        let date_format = DateFormat::new("%Y-%m-%d %H:%M %z".to_string()).unwrap();
+        assert_eq!(
+            date_format.format_timestamp(1634567890, 0),
+            "2021-10-18 14:38 +0000"
+        );
+        assert_eq!(
+            date_format.format_timestamp(1634567890, 9 * 60),
+            "2021-10-18 23:38 +0900"
+        );
+        assert_eq!(
+            date_format.format_timestamp(1634567890, -(5 * 60 + 30)),
+            "2021-10-18 09:08 -0530"
+        );
+    }
+
+    #[test]
+    fn test_default_date_format() {
+        assert_eq!(DateFormat::default().as_str(), "%Y-%m-%d %H:%M:%S");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::domain::value_objects::FilePath;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+#[derive(Debug, Clone)]
+pub struct ExcludePatterns {
+    patterns: Vec<String>,
+    glob_set: GlobSet,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExcludePatternsError {
+    #[error("Invalid exclude pattern '{pattern}': {message}")]
+    InvalidPattern { pattern: String, message: String },
+}
+
+impl ExcludePatterns {
+    /// File in the repository root listing more patterns, one per line.
+    pub const IGNORE_FILE: &'static str = ".arlonignore";
+
+    /// Patterns without a `/` match the file name at any depth, like
+    /// `.gitignore`; other patterns are matched against the full path.
+    pub fn new(patterns: Vec<String>) -> Result<Self, ExcludePatternsError> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in &patterns {
+            let anchored = if pattern.contains('/') {
+                pattern.trim_start_matches('/').to_string()
+            } else {
+                format!("**/{}", pattern)
+            };
+            let glob = Glob::new(&anchored).map_err(|e| ExcludePatternsError::InvalidPattern {
+                pattern: pattern.clone(),
+                message: e.kind().to_string(),
+            })?;
+            builder.add(glob);
+        }
+
+        let glob_set = builder
+            .build()
+            .map_err(|e| ExcludePatternsError::InvalidPattern {
+                pattern: patterns.join(", "),
+                message: e.to_string(),
+            })?;
+
+        Ok(Self { patterns, glob_set })
+    }
+
+    /// Adds the patterns of an ignore file, skipping blank lines and `#`
+    /// comments.
+    pub fn with_ignore_file(self, contents: &str) -> Result<Self, ExcludePatternsError> {
+        let mut patterns = self.patterns;
+        patterns.extend(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        );
+        Self::new(patterns)
+    }
+
+    pub fn is_excluded(&self, path: &FilePath) -> bool {
+        self.glob_set.is_match(path.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+}
+
+impl Default for ExcludePatterns {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            glob_set: GlobSet::empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(path: &str) -> FilePath {
+        FilePath::new(path.to_string()).unwrap()
+    }
+
+    fn patterns(patterns: &[&str]) -> ExcludePatterns {
+        ExcludePatterns::new(patterns.iter().map(|p| p.to_string()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_basename_pattern_matches_at_any_depth() {
+        let excludes = patterns(&["Cargo.lock", "*.snap"]);
+
+        assert!(excludes.is_excluded(&path("Cargo.lock")));
+        assert!(excludes.is_excluded(&path("cli/Cargo.lock")));
+        assert!(excludes.is_excluded(&path("tests/snapshots/output.snap")));
+        assert!(!excludes.is_excluded(&path("src/main.rs")));
+    }
+
+    #[test]
+    fn test_path_pattern_is_anchored() {
+        let excludes = patterns(&["/docs/**", "generated/*.rs"]);
+
+        assert!(excludes.is_excluded(&path("docs/guide/index.md")));
+        assert!(excludes.is_excluded(&path("generated/schema.rs")));
+        assert!(!excludes.is_excluded(&path("core/generated/schema.rs")));
+    }
+
+    #[test]
+    fn test_with_ignore_file() {
+        let excludes = patterns(&["Cargo.lock"])
+            .with_ignore_file("# Generated\n\n*.snap\n  /docs/**  \n")
+            .unwrap();
+
+        assert_eq!(excludes.patterns(), ["Cargo.lock", "*.snap", "/docs/**"]);
+        assert!(excludes.is_excluded(&path("docs/index.md")));
+        assert!(!excludes.is_excluded(&path("# Generated")));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = ExcludePatterns::new(vec!["src/[".to_string()]);
+        assert!(matches!(
+            result.unwrap_err(),
+            ExcludePatternsError::InvalidPattern { .. }
+        ));
+    }
+
+    #[test]
+    fn test_default_is_empty() {
+        let excludes = ExcludePatterns::default();
+        assert!(excludes.is_empty());
+        assert!(!excludes.is_excluded(&path("Cargo.lock")));
+    }
+}
@@ -0,0 +1,102 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenameDetection {
+    #[default]
+    Disabled,
+    Enabled {
+        threshold: u16,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RenameDetectionError {
+    #[error("Rename threshold must be between 0 and 100: {0}")]
+    InvalidThreshold(u16),
+}
+
+impl RenameDetection {
+    pub const DEFAULT_THRESHOLD: u16 = 50;
+
+    pub fn enabled(threshold: u16) -> Result<Self, RenameDetectionError> {
+        if threshold > 100 {
+            return Err(RenameDetectionError::InvalidThreshold(threshold));
+        }
+
+        Ok(Self::Enabled { threshold })
+    }
+}
+
+/// Whitespace differences to ignore, as the `git diff` options of the same
+/// names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WhitespaceOptions {
+    pub ignore_all_space: bool,
+    pub ignore_space_change: bool,
+    pub ignore_blank_lines: bool,
+    pub ignore_cr_at_eol: bool,
+}
+
+impl WhitespaceOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.ignore_all_space
+            || self.ignore_space_change
+            || self.ignore_blank_lines
+            || self.ignore_cr_at_eol
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileComparisonOptions {
+    pub renames: RenameDetection,
+    /// Maximum number of files considered for rename detection; `None`
+    /// uses the libgit2 default.
+    pub rename_limit: Option<usize>,
+    /// Count lines added and removed per file.
+    pub line_stats: bool,
+    /// Build a patch for each file with this many lines of context around
+    /// changes; `None` builds no patches.
+    pub patch_context: Option<u32>,
+    /// Whitespace to ignore; files differing only in it are marked as
+    /// whitespace-only.
+    pub whitespace: WhitespaceOptions,
+    /// List the commits between the old and new pointers of changed
+    /// submodules that are checked out.
+    pub recurse_submodules: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_detection_enabled() {
+        let renames = RenameDetection::enabled(60).unwrap();
+        assert_eq!(renames, RenameDetection::Enabled { threshold: 60 });
+    }
+
+    #[test]
+    fn test_rename_detection_invalid_threshold() {
+        let result = RenameDetection::enabled(101);
+        assert!(matches!(
+            result.unwrap_err(),
+            RenameDetectionError::InvalidThreshold(101)
+        ));
+    }
+
+    #[test]
+    fn test_default_options() {
+        let options = FileComparisonOptions::default();
+        assert_eq!(options.renames, RenameDetection::Disabled);
+        assert_eq!(options.rename_limit, None);
+        assert!(!options.line_stats);
+        assert!(!options.whitespace.is_enabled());
+    }
+
+    #[test]
+    fn test_whitespace_options_enabled() {
+        let whitespace = WhitespaceOptions {
+            ignore_blank_lines: true,
+            ..WhitespaceOptions::default()
+        };
+        assert!(whitespace.is_enabled());
+    }
+}
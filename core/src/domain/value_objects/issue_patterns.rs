@@ -0,0 +1,103 @@
+use regex::Regex;
+
+/// Regular expressions that find issue-tracker references in commit
+/// messages. A pattern with a capture group contributes the first group;
+/// one without contributes the whole match.
+#[derive(Debug, Clone)]
+pub struct IssuePatterns {
+    patterns: Vec<Regex>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssuePatternsError {
+    #[error("Invalid issue pattern '{pattern}': {message}")]
+    InvalidPattern { pattern: String, message: String },
+}
+
+impl IssuePatterns {
+    /// GitHub-style `#123` and Jira-style `ABC-123` references.
+    pub const DEFAULT_PATTERNS: [&'static str; 2] = [r"\B#\d+\b", r"\b[A-Z][A-Z0-9]+-\d+\b"];
+
+    pub fn new(patterns: Vec<String>) -> Result<Self, IssuePatternsError> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| IssuePatternsError::InvalidPattern {
+                    pattern: pattern.clone(),
+                    message: e.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { patterns })
+    }
+
+    /// Unique references in `text`, in order of first appearance.
+    pub fn find_all(&self, text: &str) -> Vec<String> {
+        let mut matches: Vec<(usize, &str)> = self
+            .patterns
+            .iter()
+            .flat_map(|pattern| pattern.captures_iter(text))
+            .filter_map(|captures| captures.get(1).or_else(|| captures.get(0)))
+            .map(|found| (found.start(), found.as_str()))
+            .collect();
+        matches.sort_by_key(|(start, _)| *start);
+
+        let mut issues: Vec<String> = Vec::new();
+        for (_, issue) in matches {
+            if !issues.iter().any(|known| known == issue) {
+                issues.push(issue.to_string());
+            }
+        }
+        issues
+    }
+}
+
+impl Default for IssuePatterns {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+        )
+        .expect("default issue patterns are valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_patterns() {
+        let patterns = IssuePatterns::default();
+
+        assert_eq!(
+            patterns.find_all("PROJ-12: fix login (#34), see #34 and PROJ-7"),
+            vec!["PROJ-12", "#34", "PROJ-7"]
+        );
+        assert!(patterns.find_all("abc#12 and lowercase-12").is_empty());
+    }
+
+    #[test]
+    fn test_capture_group() {
+        let patterns =
+            IssuePatterns::new(vec![r"https://tracker\.example/(\d+)".to_string()]).unwrap();
+
+        assert_eq!(
+            patterns.find_all("See https://tracker.example/981"),
+            vec!["981"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = IssuePatterns::new(vec!["(".to_string()]);
+
+        assert!(matches!(
+            result,
+            Err(IssuePatternsError::InvalidPattern { pattern, .. }) if pattern == "("
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use std::ops::AddAssign;
+
+/// Lines added and removed by a change.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineStats {
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl LineStats {
+    pub fn new(added: usize, removed: usize) -> Self {
+        Self { added, removed }
+    }
+}
+
+impl AddAssign for LineStats {
+    fn add_assign(&mut self, other: Self) {
+        self.added += other.added;
+        self.removed += other.removed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_stats_add_assign() {
+        let mut stats = LineStats::new(3, 1);
+        stats += LineStats::new(2, 5);
+        assert_eq!(stats, LineStats::new(5, 6));
+    }
+}
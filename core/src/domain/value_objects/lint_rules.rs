@@ -0,0 +1,180 @@
+/// A check run over each commit message by `arlon lint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintRule {
+    SubjectLength,
+    TrailingPeriod,
+    ImperativeMood,
+    ConventionalCommits,
+    NoFixup,
+    NoWip,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 6] = [
+        LintRule::SubjectLength,
+        LintRule::TrailingPeriod,
+        LintRule::ImperativeMood,
+        LintRule::ConventionalCommits,
+        LintRule::NoFixup,
+        LintRule::NoWip,
+    ];
+
+    /// Rules enabled when the config does not list any. Conventional
+    /// Commits is opt-in since many projects do not follow it.
+    pub const DEFAULT: [LintRule; 5] = [
+        LintRule::SubjectLength,
+        LintRule::TrailingPeriod,
+        LintRule::ImperativeMood,
+        LintRule::NoFixup,
+        LintRule::NoWip,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SubjectLength => "subject-length",
+            Self::TrailingPeriod => "trailing-period",
+            Self::ImperativeMood => "imperative-mood",
+            Self::ConventionalCommits => "conventional-commits",
+            Self::NoFixup => "no-fixup",
+            Self::NoWip => "no-wip",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<LintRule> {
+        Self::ALL.into_iter().find(|rule| rule.as_str() == name)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LintRulesError {
+    #[error("Unknown lint rule '{0}' (expected one of {names})", names = rule_names())]
+    UnknownRule(String),
+    #[error("Maximum subject length must be at least 1")]
+    InvalidSubjectLength,
+}
+
+fn rule_names() -> String {
+    LintRule::ALL.map(|rule| rule.as_str()).join(", ")
+}
+
+/// The rules `arlon lint` applies and their settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintRules {
+    rules: Vec<LintRule>,
+    max_subject_length: usize,
+}
+
+impl LintRules {
+    /// The conventional limit that keeps subjects readable in `git log --oneline`.
+    pub const DEFAULT_MAX_SUBJECT_LENGTH: usize = 72;
+
+    /// Enables the rules named in `names`, or the default rules when
+    /// `names` is `None`.
+    pub fn new(
+        names: Option<Vec<String>>,
+        max_subject_length: usize,
+    ) -> Result<Self, LintRulesError> {
+        if max_subject_length == 0 {
+            return Err(LintRulesError::InvalidSubjectLength);
+        }
+
+        let mut rules = match names {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    LintRule::from_name(name)
+                        .ok_or_else(|| LintRulesError::UnknownRule(name.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => LintRule::DEFAULT.to_vec(),
+        };
+        rules.sort();
+        rules.dedup();
+
+        Ok(Self {
+            rules,
+            max_subject_length,
+        })
+    }
+
+    pub fn rules(&self) -> &[LintRule] {
+        &self.rules
+    }
+
+    pub fn max_subject_length(&self) -> usize {
+        self.max_subject_length
+    }
+}
+
+impl Default for LintRules {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::DEFAULT.to_vec(),
+            max_subject_length: Self::DEFAULT_MAX_SUBJECT_LENGTH,
+        }
+    }
+}
+
+/// A rule a commit message breaks, with a description of how.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintViolation {
+    rule: LintRule,
+    message: String,
+}
+
+impl LintViolation {
+    pub fn new(rule: LintRule, message: String) -> Self {
+        Self { rule, message }
+    }
+
+    pub fn rule(&self) -> LintRule {
+        self.rule
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let rules = LintRules::new(
+            Some(vec![
+                "no-wip".to_string(),
+                "conventional-commits".to_string(),
+                "no-wip".to_string(),
+            ]),
+            50,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules.rules(),
+            &[LintRule::ConventionalCommits, LintRule::NoWip]
+        );
+        assert_eq!(rules.max_subject_length(), 50);
+        assert_eq!(
+            LintRules::new(None, LintRules::DEFAULT_MAX_SUBJECT_LENGTH).unwrap(),
+            LintRules::default()
+        );
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        let result = LintRules::new(Some(vec!["no-typos".to_string()]), 72);
+        let error = result.unwrap_err();
+        assert!(matches!(&error, LintRulesError::UnknownRule(name) if name == "no-typos"));
+        assert!(error
+            .to_string()
+            .contains("subject-length, trailing-period"));
+
+        assert!(matches!(
+            LintRules::new(None, 0),
+            Err(LintRulesError::InvalidSubjectLength)
+        ));
+    }
+}
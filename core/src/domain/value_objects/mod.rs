@@ -1,7 +1,43 @@
 pub mod branch_name;
+pub mod code_owners;
 pub mod commit_hash;
+pub mod commit_signature;
+pub mod commit_warning;
+pub mod comparison_endpoints;
+pub mod conventional_commit;
+pub mod date_format;
+pub mod exclude_patterns;
+pub mod file_comparison_options;
 pub mod file_path;
+pub mod issue_patterns;
+pub mod line_stats;
+pub mod lint_rules;
+pub mod package_globs;
+pub mod required_trailers;
+pub mod text_encoding;
+pub mod trailer;
+pub mod version_bump;
+pub mod workspace_members;
 
 pub use branch_name::{BranchName, BranchNameError};
+pub use code_owners::CodeOwners;
 pub use commit_hash::{CommitHash, CommitHashError};
+pub use commit_signature::{SignatureFormat, SignatureStatus, SignatureVerification};
+pub use commit_warning::CommitWarning;
+pub use comparison_endpoints::ComparisonEndpoints;
+pub use conventional_commit::{ConventionalCommit, Footer};
+pub use date_format::{DateFormat, DateFormatError};
+pub use exclude_patterns::{ExcludePatterns, ExcludePatternsError};
+pub use file_comparison_options::{
+    FileComparisonOptions, RenameDetection, RenameDetectionError, WhitespaceOptions,
+};
 pub use file_path::{FilePath, FilePathError};
+pub use issue_patterns::{IssuePatterns, IssuePatternsError};
+pub use line_stats::LineStats;
+pub use lint_rules::{LintRule, LintRules, LintRulesError, LintViolation};
+pub use package_globs::{PackageGlobs, PackageGlobsError};
+pub use required_trailers::{RequiredTrailers, RequiredTrailersError};
+pub use text_encoding::{TextEncoding, TextEncodingError};
+pub use trailer::Trailer;
+pub use version_bump::VersionBump;
+pub use workspace_members::{PackageManager, WorkspaceMembers, WorkspaceMembersError};
@@ -0,0 +1,113 @@
+use crate::domain::value_objects::FilePath;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::BTreeMap;
+
+/// Packages defined by hand as a map from package name to path patterns,
+/// for repositories whose layout is not described by a workspace manifest.
+#[derive(Debug, Clone, Default)]
+pub struct PackageGlobs {
+    packages: Vec<(String, GlobSet)>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PackageGlobsError {
+    #[error("Invalid pattern '{pattern}' for package '{package}': {message}")]
+    InvalidPattern {
+        package: String,
+        pattern: String,
+        message: String,
+    },
+}
+
+impl PackageGlobs {
+    /// Patterns are matched against the full path. A pattern also matches
+    /// everything below it, so a plain directory name covers its contents.
+    pub fn new(packages: BTreeMap<String, Vec<String>>) -> Result<Self, PackageGlobsError> {
+        let packages = packages
+            .into_iter()
+            .map(|(package, patterns)| {
+                let mut builder = GlobSetBuilder::new();
+                for pattern in &patterns {
+                    let invalid = |message: String| PackageGlobsError::InvalidPattern {
+                        package: package.clone(),
+                        pattern: pattern.clone(),
+                        message,
+                    };
+                    let anchored = pattern.trim_start_matches('/').trim_end_matches('/');
+                    for glob in [anchored.to_string(), format!("{}/**", anchored)] {
+                        builder.add(Glob::new(&glob).map_err(|e| invalid(e.kind().to_string()))?);
+                    }
+                }
+                let glob_set = builder
+                    .build()
+                    .map_err(|e| PackageGlobsError::InvalidPattern {
+                        package: package.clone(),
+                        pattern: patterns.join(", "),
+                        message: e.to_string(),
+                    })?;
+                Ok((package, glob_set))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { packages })
+    }
+
+    /// Names of the packages containing `path`, in name order.
+    pub fn packages_of(&self, path: &FilePath) -> Vec<&str> {
+        self.packages
+            .iter()
+            .filter(|(_, glob_set)| glob_set.is_match(path.as_str()))
+            .map(|(package, _)| package.as_str())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(path: &str) -> FilePath {
+        FilePath::new(path.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_packages_of() {
+        let packages = PackageGlobs::new(BTreeMap::from([
+            (
+                "web".to_string(),
+                vec!["apps/web".to_string(), "shared/ui/**".to_string()],
+            ),
+            ("protos".to_string(), vec!["**/*.proto".to_string()]),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            packages.packages_of(&path("apps/web/src/index.ts")),
+            vec!["web"]
+        );
+        assert_eq!(
+            packages.packages_of(&path("shared/ui/api.proto")),
+            vec!["protos", "web"]
+        );
+        assert!(packages
+            .packages_of(&path("apps/website/index.ts"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = PackageGlobs::new(BTreeMap::from([(
+            "web".to_string(),
+            vec!["apps/[web".to_string()],
+        )]));
+
+        assert!(matches!(
+            result,
+            Err(PackageGlobsError::InvalidPattern { package, .. }) if package == "web"
+        ));
+    }
+}
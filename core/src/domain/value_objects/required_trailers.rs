@@ -0,0 +1,63 @@
+use crate::domain::value_objects::Trailer;
+
+/// Trailers every commit must carry, e.g. `Signed-off-by` for the
+/// Developer Certificate of Origin.
+#[derive(Debug, Clone, Default)]
+pub struct RequiredTrailers {
+    tokens: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RequiredTrailersError {
+    #[error("Invalid trailer '{0}': use letters, digits and '-'")]
+    InvalidToken(String),
+}
+
+impl RequiredTrailers {
+    pub fn new(tokens: Vec<String>) -> Result<Self, RequiredTrailersError> {
+        if let Some(token) = tokens.iter().find(|token| !Trailer::is_valid_token(token)) {
+            return Err(RequiredTrailersError::InvalidToken(token.clone()));
+        }
+
+        Ok(Self { tokens })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// The required tokens that none of `trailers` has.
+    pub fn missing(&self, trailers: &[Trailer]) -> Vec<String> {
+        self.tokens
+            .iter()
+            .filter(|token| !trailers.iter().any(|trailer| trailer.has_token(token)))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing() {
+        let required =
+            RequiredTrailers::new(vec!["Signed-off-by".to_string(), "Reviewed-by".to_string()])
+                .unwrap();
+        let trailers = Trailer::parse_all("SIGNED-OFF-BY: Alice <alice@example.com>");
+
+        assert_eq!(required.missing(&trailers), vec!["Reviewed-by"]);
+        assert_eq!(required.missing(&[]).len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_token() {
+        let result = RequiredTrailers::new(vec!["Signed off by".to_string()]);
+
+        assert!(matches!(
+            result,
+            Err(RequiredTrailersError::InvalidToken(token)) if token == "Signed off by"
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use encoding_rs::{Encoding, UTF_8};
+use std::borrow::Cow;
+use std::fmt;
+
+/// A character encoding identified by a WHATWG label such as `UTF-8`,
+/// `ISO-8859-1` or `Shift_JIS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEncoding(&'static Encoding);
+
+#[derive(Debug, thiserror::Error)]
+pub enum TextEncodingError {
+    #[error("Unknown encoding: {0}")]
+    Unknown(String),
+}
+
+impl TextEncoding {
+    pub fn new(label: &str) -> Result<Self, TextEncodingError> {
+        Encoding::for_label(label.trim().as_bytes())
+            .map(Self)
+            .ok_or_else(|| TextEncodingError::Unknown(label.to_string()))
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    pub fn is_utf8(&self) -> bool {
+        self.0 == UTF_8
+    }
+
+    /// Decodes `bytes`, replacing malformed sequences.
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Cow<'a, str> {
+        self.0.decode_without_bom_handling(bytes).0
+    }
+
+    /// Encodes `text`, replacing unmappable characters with numeric
+    /// character references.
+    pub fn encode<'a>(&self, text: &'a str) -> Cow<'a, [u8]> {
+        self.0.encode(text).0
+    }
+}
+
+impl Default for TextEncoding {
+    fn default() -> Self {
+        Self(UTF_8)
+    }
+}
+
+impl fmt::Display for TextEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_encoding_new() {
+        let encoding = TextEncoding::new("latin1").unwrap();
+        assert_eq!(encoding.name(), "windows-1252");
+        assert!(!encoding.is_utf8());
+        assert!(TextEncoding::default().is_utf8());
+    }
+
+    #[test]
+    fn test_text_encoding_unknown() {
+        let result = TextEncoding::new("klingon");
+        assert!(matches!(result.unwrap_err(), TextEncodingError::Unknown(_)));
+    }
+
+    #[test]
+    fn test_text_encoding_round_trip() {
+        let encoding = TextEncoding::new("ISO-8859-1").unwrap();
+
+        assert_eq!(encoding.encode("café").as_ref(), b"caf\xe9");
+        assert_eq!(encoding.decode(b"caf\xe9"), "café");
+    }
+}
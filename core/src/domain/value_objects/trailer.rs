@@ -0,0 +1,141 @@
+/// A `Token: value` line from the trailer block at the end of a commit
+/// message, such as `Signed-off-by: Alice <alice@example.com>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trailer {
+    token: String,
+    value: String,
+}
+
+/// Trailers git adds itself; one of these lets a block that is only partly
+/// made of trailers still count as the trailer block.
+const GIT_GENERATED_TOKENS: [&str; 1] = ["Signed-off-by"];
+
+impl Trailer {
+    /// Parses the trailers in the last paragraph of `body` the way
+    /// `git interpret-trailers --parse` does: the paragraph must consist of
+    /// trailers, or be at least a quarter trailers including one that git
+    /// generates. Indented lines continue the previous trailer.
+    pub fn parse_all(body: &str) -> Vec<Trailer> {
+        let body = body.replace("\r\n", "\n");
+        let Some(paragraph) = body
+            .trim_end()
+            .rsplit("\n\n")
+            .find(|paragraph| !paragraph.trim().is_empty())
+        else {
+            return Vec::new();
+        };
+
+        let mut trailers: Vec<Trailer> = Vec::new();
+        let mut other_lines = 0;
+        let mut last_was_trailer = false;
+        for line in paragraph.lines() {
+            if line.starts_with([' ', '\t']) && last_was_trailer {
+                if let Some(trailer) = trailers.last_mut() {
+                    trailer.value.push(' ');
+                    trailer.value.push_str(line.trim());
+                }
+                continue;
+            }
+
+            match Trailer::parse_line(line) {
+                Some(trailer) => {
+                    trailers.push(trailer);
+                    last_was_trailer = true;
+                }
+                None => {
+                    other_lines += 1;
+                    last_was_trailer = false;
+                }
+            }
+        }
+
+        let git_generated = trailers.iter().any(|trailer| {
+            GIT_GENERATED_TOKENS
+                .iter()
+                .any(|token| trailer.has_token(token))
+        });
+        if other_lines == 0 || (git_generated && trailers.len() * 3 >= other_lines) {
+            trailers
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn parse_line(line: &str) -> Option<Trailer> {
+        let (token, value) = line.split_once(':')?;
+        let token = token.trim_end();
+        Trailer::is_valid_token(token).then(|| Trailer {
+            token: token.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+
+    /// Whether `token` can name a trailer: letters, digits and `-`.
+    pub fn is_valid_token(token: &str) -> bool {
+        !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Whether this trailer is a `token` trailer; git ignores case.
+    pub fn has_token(&self, token: &str) -> bool {
+        self.token.eq_ignore_ascii_case(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(trailers: &[Trailer]) -> Vec<(&str, &str)> {
+        trailers
+            .iter()
+            .map(|trailer| (trailer.token(), trailer.value()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let body = "Explain the change: in prose.\n\nSigned-off-by: Alice <alice@example.com>\nCo-authored-by: Bob\n  <bob@example.com>\nReviewed-by:Carol\n";
+
+        assert_eq!(
+            pairs(&Trailer::parse_all(body)),
+            vec![
+                ("Signed-off-by", "Alice <alice@example.com>"),
+                ("Co-authored-by", "Bob <bob@example.com>"),
+                ("Reviewed-by", "Carol"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_all_ignores_prose() {
+        assert!(Trailer::parse_all("").is_empty());
+        assert!(Trailer::parse_all("This line is prose.\nReviewed-by: Carol").is_empty());
+        assert!(Trailer::parse_all("Reviewed-by: Carol\n\nA closing remark.").is_empty());
+    }
+
+    #[test]
+    fn test_parse_all_with_git_generated_trailer() {
+        let body = "Fix the parser.\nSigned-off-by: Alice <alice@example.com>";
+
+        assert_eq!(
+            pairs(&Trailer::parse_all(body)),
+            vec![("Signed-off-by", "Alice <alice@example.com>")]
+        );
+    }
+
+    #[test]
+    fn test_has_token_ignores_case() {
+        let trailers = Trailer::parse_all("signed-off-by: Alice");
+
+        assert!(trailers[0].has_token("Signed-off-by"));
+        assert!(!trailers[0].has_token("Signed-off"));
+    }
+}
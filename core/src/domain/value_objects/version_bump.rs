@@ -0,0 +1,82 @@
+/// The Semantic Versioning component to increment for a set of changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum VersionBump {
+    #[default]
+    None,
+    Patch,
+    Minor,
+    Major,
+}
+
+impl VersionBump {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        }
+    }
+
+    /// Applies the bump to a version such as `1.4.2` or `v1.4.2`, keeping
+    /// the `v` prefix and dropping pre-release and build metadata. Returns
+    /// `None` when `version` is not a version or there is nothing to bump.
+    pub fn apply(&self, version: &str) -> Option<String> {
+        if *self == Self::None {
+            return None;
+        }
+
+        let (prefix, version) = match version.strip_prefix('v') {
+            Some(version) => ("v", version),
+            None => ("", version),
+        };
+        let core = version.split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        let (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return None;
+        };
+
+        let (major, minor, patch) = match self {
+            Self::Major => (major + 1, 0, 0),
+            Self::Minor => (major, minor + 1, 0),
+            Self::Patch => (major, minor, patch + 1),
+            Self::None => unreachable!(),
+        };
+        Some(format!("{}{}.{}.{}", prefix, major, minor, patch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(
+            VersionBump::Major.apply("v1.4.2"),
+            Some("v2.0.0".to_string())
+        );
+        assert_eq!(VersionBump::Minor.apply("1.4.2"), Some("1.5.0".to_string()));
+        assert_eq!(
+            VersionBump::Patch.apply("v1.4.2-rc.1"),
+            Some("v1.4.3".to_string())
+        );
+        assert_eq!(VersionBump::None.apply("v1.4.2"), None);
+    }
+
+    #[test]
+    fn test_apply_to_non_versions() {
+        assert_eq!(VersionBump::Minor.apply("main"), None);
+        assert_eq!(VersionBump::Minor.apply("v1.4"), None);
+        assert_eq!(VersionBump::Minor.apply("1.4.2.1"), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(VersionBump::Major > VersionBump::Minor);
+        assert!(VersionBump::Minor > VersionBump::Patch);
+        assert!(VersionBump::Patch > VersionBump::None);
+    }
+}
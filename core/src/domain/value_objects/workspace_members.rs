@@ -0,0 +1,266 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Package managers whose workspace manifests are understood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PackageManager {
+    Cargo,
+    Npm,
+}
+
+impl PackageManager {
+    pub const ALL: [PackageManager; 2] = [PackageManager::Cargo, PackageManager::Npm];
+
+    /// File name of the manifest at the workspace root and in each member.
+    pub fn manifest(&self) -> &'static str {
+        match self {
+            Self::Cargo => "Cargo.toml",
+            Self::Npm => "package.json",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Npm => "npm",
+        }
+    }
+
+    /// The package name declared in a member's manifest.
+    pub fn package_name(&self, manifest: &str) -> Option<String> {
+        match self {
+            Self::Cargo => {
+                let table: toml::Table = toml::from_str(manifest).ok()?;
+                let name = table.get("package")?.get("name")?.as_str()?;
+                Some(name.to_string())
+            }
+            Self::Npm => {
+                let value: serde_json::Value = serde_json::from_str(manifest).ok()?;
+                Some(value.get("name")?.as_str()?.to_string())
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceMembersError {
+    #[error("Invalid {manifest}: {message}")]
+    InvalidManifest {
+        manifest: &'static str,
+        message: String,
+    },
+    #[error("Invalid workspace member pattern '{pattern}': {message}")]
+    InvalidPattern { pattern: String, message: String },
+}
+
+/// Member directories declared by a Cargo `[workspace]` or npm
+/// `workspaces` entry in the repository's root manifest.
+#[derive(Debug, Clone)]
+pub struct WorkspaceMembers {
+    manager: PackageManager,
+    include: GlobSet,
+    exclude: GlobSet,
+}
+
+impl WorkspaceMembers {
+    /// Parses the root manifest of `manager`. Returns `None` when the
+    /// manifest does not declare a workspace.
+    pub fn parse(
+        manager: PackageManager,
+        manifest: &str,
+    ) -> Result<Option<Self>, WorkspaceMembersError> {
+        let invalid = |message: String| WorkspaceMembersError::InvalidManifest {
+            manifest: manager.manifest(),
+            message,
+        };
+
+        let (mut include, exclude) = match manager {
+            PackageManager::Cargo => {
+                let table: toml::Table =
+                    toml::from_str(manifest).map_err(|e| invalid(e.message().to_string()))?;
+                let Some(workspace) = table.get("workspace") else {
+                    return Ok(None);
+                };
+                (
+                    string_array(workspace.get("members")),
+                    string_array(workspace.get("exclude")),
+                )
+            }
+            PackageManager::Npm => {
+                let value: serde_json::Value =
+                    serde_json::from_str(manifest).map_err(|e| invalid(e.to_string()))?;
+                let patterns = match value.get("workspaces") {
+                    Some(serde_json::Value::Array(patterns)) => patterns,
+                    Some(serde_json::Value::Object(workspaces)) => {
+                        match workspaces.get("packages") {
+                            Some(serde_json::Value::Array(patterns)) => patterns,
+                            _ => return Ok(None),
+                        }
+                    }
+                    _ => return Ok(None),
+                };
+                let patterns: Vec<&str> = patterns.iter().filter_map(|p| p.as_str()).collect();
+                (
+                    patterns
+                        .iter()
+                        .filter(|pattern| !pattern.starts_with('!'))
+                        .map(|pattern| pattern.to_string())
+                        .collect(),
+                    patterns
+                        .iter()
+                        .filter_map(|pattern| pattern.strip_prefix('!'))
+                        .map(|pattern| pattern.to_string())
+                        .collect(),
+                )
+            }
+        };
+
+        // A root manifest that is also a package makes the root a member.
+        if manager == PackageManager::Cargo && manager.package_name(manifest).is_some() {
+            include.push(".".to_string());
+        }
+
+        Ok(Some(Self {
+            manager,
+            include: build_glob_set(&include)?,
+            exclude: build_glob_set(&exclude)?,
+        }))
+    }
+
+    pub fn manager(&self) -> PackageManager {
+        self.manager
+    }
+
+    /// Whether `directory`, relative to the repository root and empty for
+    /// the root itself, matches a member pattern.
+    pub fn is_member(&self, directory: &str) -> bool {
+        let directory = if directory.is_empty() { "." } else { directory };
+        self.include.is_match(directory) && !self.exclude.is_match(directory)
+    }
+}
+
+fn string_array(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|value| value.as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_glob_set(patterns: &[String]) -> Result<GlobSet, WorkspaceMembersError> {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let normalized = pattern.trim_start_matches("./").trim_end_matches('/');
+        let normalized = if normalized.is_empty() {
+            "."
+        } else {
+            normalized
+        };
+        let glob = GlobBuilder::new(normalized)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| WorkspaceMembersError::InvalidPattern {
+                pattern: pattern.clone(),
+                message: e.kind().to_string(),
+            })?;
+        builder.add(glob);
+    }
+
+    builder
+        .build()
+        .map_err(|e| WorkspaceMembersError::InvalidPattern {
+            pattern: patterns.join(", "),
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cargo_workspace() {
+        let members = WorkspaceMembers::parse(
+            PackageManager::Cargo,
+            r#"
+            [workspace]
+            members = ["cli", "crates/*"]
+            exclude = ["crates/legacy"]
+            "#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(members.is_member("cli"));
+        assert!(members.is_member("crates/parser"));
+        assert!(!members.is_member("crates/legacy"));
+        assert!(!members.is_member("crates/parser/src"));
+        assert!(!members.is_member(""));
+    }
+
+    #[test]
+    fn test_parse_cargo_root_package() {
+        let members = WorkspaceMembers::parse(
+            PackageManager::Cargo,
+            "[package]\nname = \"root\"\n\n[workspace]\nmembers = [\"./tools/\"]\n",
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(members.is_member(""));
+        assert!(members.is_member("tools"));
+    }
+
+    #[test]
+    fn test_parse_without_workspace() {
+        let cargo = WorkspaceMembers::parse(PackageManager::Cargo, "[package]\nname = \"a\"\n");
+        assert!(cargo.unwrap().is_none());
+
+        let npm = WorkspaceMembers::parse(PackageManager::Npm, r#"{"name": "a"}"#);
+        assert!(npm.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_npm_workspaces() {
+        let members = WorkspaceMembers::parse(
+            PackageManager::Npm,
+            r#"{"workspaces": {"packages": ["packages/*", "!packages/internal"]}}"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(members.is_member("packages/web"));
+        assert!(!members.is_member("packages/internal"));
+        assert!(!members.is_member("apps/web"));
+    }
+
+    #[test]
+    fn test_parse_invalid_manifest() {
+        let result = WorkspaceMembers::parse(PackageManager::Npm, "{");
+        assert!(matches!(
+            result,
+            Err(WorkspaceMembersError::InvalidManifest {
+                manifest: "package.json",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(
+            PackageManager::Cargo.package_name("[package]\nname = \"arlon-core\"\n"),
+            Some("arlon-core".to_string())
+        );
+        assert_eq!(
+            PackageManager::Npm.package_name(r#"{"name": "@acme/web"}"#),
+            Some("@acme/web".to_string())
+        );
+        assert_eq!(PackageManager::Npm.package_name("{}"), None);
+    }
+}
@@ -0,0 +1,243 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Contents of `.arlon.toml` or the user config file. Every field is
+/// optional so that files can be layered on top of each other.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    pub base: Option<String>,
+    pub format: Option<String>,
+    pub date_format: Option<String>,
+    pub renames: Option<RenamesConfig>,
+    pub exclude: Option<Vec<String>>,
+    pub abbrev: Option<usize>,
+    pub encoding: Option<String>,
+    /// Package name to path patterns, used by `arlon affected` instead of
+    /// workspace manifests.
+    pub packages: Option<BTreeMap<String, Vec<String>>>,
+    /// Regular expressions for issue references, replacing the defaults.
+    pub issue_patterns: Option<Vec<String>>,
+    pub lint: Option<LintConfig>,
+    pub signing: Option<SigningConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RenamesConfig {
+    pub enabled: Option<bool>,
+    pub threshold: Option<u16>,
+    pub limit: Option<usize>,
+}
+
+/// The `[lint]` table, configuring `arlon lint`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfig {
+    /// Names of the rules to apply, replacing the default set.
+    pub rules: Option<Vec<String>>,
+    pub max_subject_length: Option<usize>,
+}
+
+/// The `[signing]` table, naming the keys `--verify-signatures` trusts.
+/// Only read from the user config, so that a repository cannot supply the
+/// keys its own commits are checked against. Relative paths are resolved
+/// against the directory of the config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SigningConfig {
+    /// GPG keyring to verify against instead of the default one.
+    pub gpg_keyring: Option<PathBuf>,
+    /// An SSH `allowed_signers` file, as for `gpg.ssh.allowedSignersFile`.
+    pub allowed_signers: Option<PathBuf>,
+}
+
+impl ConfigFile {
+    /// Returns `self` with unset values taken from `fallback`.
+    pub fn or(self, fallback: ConfigFile) -> ConfigFile {
+        ConfigFile {
+            base: self.base.or(fallback.base),
+            format: self.format.or(fallback.format),
+            date_format: self.date_format.or(fallback.date_format),
+            renames: match (self.renames, fallback.renames) {
+                (Some(renames), Some(fallback)) => Some(renames.or(fallback)),
+                (renames, fallback) => renames.or(fallback),
+            },
+            exclude: self.exclude.or(fallback.exclude),
+            abbrev: self.abbrev.or(fallback.abbrev),
+            encoding: self.encoding.or(fallback.encoding),
+            packages: self.packages.or(fallback.packages),
+            issue_patterns: self.issue_patterns.or(fallback.issue_patterns),
+            lint: match (self.lint, fallback.lint) {
+                (Some(lint), Some(fallback)) => Some(lint.or(fallback)),
+                (lint, fallback) => lint.or(fallback),
+            },
+            signing: match (self.signing, fallback.signing) {
+                (Some(signing), Some(fallback)) => Some(signing.or(fallback)),
+                (signing, fallback) => signing.or(fallback),
+            },
+        }
+    }
+}
+
+impl RenamesConfig {
+    pub fn or(self, fallback: RenamesConfig) -> RenamesConfig {
+        RenamesConfig {
+            enabled: self.enabled.or(fallback.enabled),
+            threshold: self.threshold.or(fallback.threshold),
+            limit: self.limit.or(fallback.limit),
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn or(self, fallback: LintConfig) -> LintConfig {
+        LintConfig {
+            rules: self.rules.or(fallback.rules),
+            max_subject_length: self.max_subject_length.or(fallback.max_subject_length),
+        }
+    }
+}
+
+impl SigningConfig {
+    /// Returns `self` with relative paths joined onto `dir`.
+    pub fn relative_to(self, dir: &Path) -> SigningConfig {
+        let resolve = |path: PathBuf| {
+            if path.is_relative() {
+                dir.join(path)
+            } else {
+                path
+            }
+        };
+        SigningConfig {
+            gpg_keyring: self.gpg_keyring.map(resolve),
+            allowed_signers: self.allowed_signers.map(resolve),
+        }
+    }
+
+    pub fn or(self, fallback: SigningConfig) -> SigningConfig {
+        SigningConfig {
+            gpg_keyring: self.gpg_keyring.or(fallback.gpg_keyring),
+            allowed_signers: self.allowed_signers.or(fallback.allowed_signers),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_file() {
+        let config: ConfigFile = toml::from_str(
+            r#"
+            base = "main"
+            format = "json"
+            date_format = "%Y-%m-%d"
+            exclude = ["Cargo.lock"]
+            abbrev = 12
+            encoding = "ISO-8859-1"
+            issue_patterns = ['ACME-\d+']
+
+            [packages]
+            web = ["apps/web", "shared/ui"]
+
+            [renames]
+            enabled = true
+            threshold = 60
+            limit = 1000
+
+            [lint]
+            rules = ["subject-length", "conventional-commits"]
+            max_subject_length = 50
+
+            [signing]
+            allowed_signers = ".github/allowed_signers"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.base.as_deref(), Some("main"));
+        assert_eq!(config.format.as_deref(), Some("json"));
+        assert_eq!(config.date_format.as_deref(), Some("%Y-%m-%d"));
+        assert_eq!(config.exclude, Some(vec!["Cargo.lock".to_string()]));
+        assert_eq!(config.abbrev, Some(12));
+        assert_eq!(config.encoding.as_deref(), Some("ISO-8859-1"));
+        assert_eq!(config.issue_patterns, Some(vec![r"ACME-\d+".to_string()]));
+        assert_eq!(
+            config.packages,
+            Some(BTreeMap::from([(
+                "web".to_string(),
+                vec!["apps/web".to_string(), "shared/ui".to_string()]
+            )]))
+        );
+        assert_eq!(
+            config.renames,
+            Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(60),
+                limit: Some(1000),
+            })
+        );
+        assert_eq!(
+            config.lint,
+            Some(LintConfig {
+                rules: Some(vec![
+                    "subject-length".to_string(),
+                    "conventional-commits".to_string()
+                ]),
+                max_subject_length: Some(50),
+            })
+        );
+        assert_eq!(
+            config.signing,
+            Some(SigningConfig {
+                gpg_keyring: None,
+                allowed_signers: Some(PathBuf::from(".github/allowed_signers")),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys() {
+        let result: Result<ConfigFile, _> = toml::from_str("branch = \"main\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_or_prefers_self() {
+        let project = ConfigFile {
+            base: Some("develop".to_string()),
+            renames: Some(RenamesConfig {
+                enabled: None,
+                threshold: Some(70),
+                limit: None,
+            }),
+            ..ConfigFile::default()
+        };
+        let user = ConfigFile {
+            base: Some("main".to_string()),
+            format: Some("json".to_string()),
+            renames: Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(50),
+                limit: Some(400),
+            }),
+            ..ConfigFile::default()
+        };
+
+        let merged = project.or(user);
+
+        assert_eq!(merged.base.as_deref(), Some("develop"));
+        assert_eq!(merged.format.as_deref(), Some("json"));
+        assert_eq!(
+            merged.renames,
+            Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: Some(70),
+                limit: Some(400),
+            })
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::infra::config::ConfigFile;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const PROJECT_CONFIG_FILE: &str = ".arlon.toml";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("Invalid config file {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+}
+
+pub struct ConfigLoader;
+
+impl ConfigLoader {
+    /// Loads `.arlon.toml` from `repo_root` layered over the user config.
+    /// The `[signing]` table is only taken from the user config.
+    pub fn load(repo_root: Option<&Path>) -> Result<ConfigFile, ConfigError> {
+        let project_path = repo_root.map(|root| root.join(PROJECT_CONFIG_FILE));
+        let user_path = Self::user_config_path();
+
+        Self::load_from(project_path.as_deref(), user_path.as_deref())
+    }
+
+    pub fn load_from(
+        project_path: Option<&Path>,
+        user_path: Option<&Path>,
+    ) -> Result<ConfigFile, ConfigError> {
+        // Trust anchors from the repository under test would let it vouch
+        // for its own signatures.
+        let project = ConfigFile {
+            signing: None,
+            ..Self::load_optional(project_path)?
+        };
+        let mut user = Self::load_optional(user_path)?;
+        if let Some(dir) = user_path.and_then(Path::parent) {
+            user.signing = user.signing.map(|signing| signing.relative_to(dir));
+        }
+
+        Ok(project.or(user))
+    }
+
+    /// `$XDG_CONFIG_HOME/arlon/config.toml`, falling back to
+    /// `~/.config/arlon/config.toml`.
+    pub fn user_config_path() -> Option<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+
+        Some(config_home.join("arlon").join("config.toml"))
+    }
+
+    fn load_optional(path: Option<&Path>) -> Result<ConfigFile, ConfigError> {
+        match path {
+            Some(path) if path.exists() => Self::load_file(path),
+            _ => Ok(ConfigFile::default()),
+        }
+    }
+
+    fn load_file(path: &Path) -> Result<ConfigFile, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        toml::from_str(&contents).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message: e.message().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::config::SigningConfig;
+
+    #[test]
+    fn test_load_from_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ConfigLoader::load_from(
+            Some(&dir.path().join(PROJECT_CONFIG_FILE)),
+            Some(&dir.path().join("config.toml")),
+        )
+        .unwrap();
+
+        assert_eq!(config, ConfigFile::default());
+    }
+
+    #[test]
+    fn test_project_config_overrides_user_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let project_path = dir.path().join(PROJECT_CONFIG_FILE);
+        let user_path = dir.path().join("config.toml");
+        fs::write(&project_path, "base = \"develop\"\n").unwrap();
+        fs::write(&user_path, "base = \"main\"\nformat = \"json\"\n").unwrap();
+
+        let config = ConfigLoader::load_from(Some(&project_path), Some(&user_path)).unwrap();
+
+        assert_eq!(config.base.as_deref(), Some("develop"));
+        assert_eq!(config.format.as_deref(), Some("json"));
+    }
+
+    #[test]
+    fn test_project_signing_config_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let project_path = dir.path().join(PROJECT_CONFIG_FILE);
+        let user_path = dir.path().join("config.toml");
+        fs::write(
+            &project_path,
+            "[signing]\nallowed_signers = \".github/allowed_signers\"\ngpg_keyring = \"keys.kbx\"\n",
+        )
+        .unwrap();
+
+        let config = ConfigLoader::load_from(Some(&project_path), None).unwrap();
+        assert_eq!(config.signing, None);
+
+        fs::write(&user_path, "[signing]\nallowed_signers = \"signers\"\n").unwrap();
+        let config = ConfigLoader::load_from(Some(&project_path), Some(&user_path)).unwrap();
+        assert_eq!(
+            config.signing,
+            Some(SigningConfig {
+                gpg_keyring: None,
+                allowed_signers: Some(dir.path().join("signers")),
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let project_path = dir.path().join(PROJECT_CONFIG_FILE);
+        fs::write(&project_path, "base = [").unwrap();
+
+        let result = ConfigLoader::load_from(Some(&project_path), None);
+
+        assert!(matches!(result.unwrap_err(), ConfigError::Parse { .. }));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::infra::config::{ConfigFile, RenamesConfig, SigningConfig};
+
+/// `core.abbrev` length used for `auto`, matching git's minimum.
+const AUTO_ABBREV: usize = 7;
+const FULL_ABBREV: usize = 40;
+
+/// Maps the git configuration keys that have an arlon equivalent onto a
+/// `ConfigFile`, to be layered below the arlon config files.
+pub struct GitConfigReader;
+
+impl GitConfigReader {
+    pub fn read(config: &git2::Config) -> ConfigFile {
+        let enabled = Self::string(config, "diff.renames").and_then(|value| parse_renames(&value));
+        let limit = config
+            .get_i64("diff.renameLimit")
+            .ok()
+            .and_then(|limit| usize::try_from(limit).ok())
+            .filter(|limit| *limit > 0);
+        let renames = (enabled.is_some() || limit.is_some()).then_some(RenamesConfig {
+            enabled,
+            threshold: None,
+            limit,
+        });
+        let signing = config
+            .get_path("gpg.ssh.allowedSignersFile")
+            .ok()
+            .map(|allowed_signers| SigningConfig {
+                gpg_keyring: None,
+                allowed_signers: Some(allowed_signers),
+            });
+
+        ConfigFile {
+            date_format: Self::string(config, "log.date").and_then(|value| date_format(&value)),
+            renames,
+            abbrev: Self::string(config, "core.abbrev").and_then(|value| parse_abbrev(&value)),
+            encoding: Self::string(config, "i18n.logOutputEncoding"),
+            signing,
+            ..ConfigFile::default()
+        }
+    }
+
+    fn string(config: &git2::Config, name: &str) -> Option<String> {
+        config.get_string(name).ok()
+    }
+}
+
+/// `diff.renames` is a boolean or `copies`; arlon does not detect copies,
+/// so `copies` just enables rename detection.
+fn parse_renames(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" | "" | "copies" | "copy" => Some(true),
+        "false" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_abbrev(value: &str) -> Option<usize> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "auto" => Some(AUTO_ABBREV),
+        "no" | "false" | "off" => Some(FULL_ABBREV),
+        other => other.parse().ok(),
+    }
+}
+
+/// Translates a `log.date` mode into a strftime format. Modes that depend
+/// on the current time or local timezone (`relative`, `human`, `local`,
+/// `format-local:`) have no equivalent and are ignored.
+fn date_format(value: &str) -> Option<String> {
+    let value = value.trim();
+    let value = value.strip_prefix("auto:").unwrap_or(value);
+
+    if let Some(format) = value.strip_prefix("format:") {
+        return Some(format.to_string());
+    }
+
+    let format = match value {
+        "default" => "%a %b %-d %H:%M:%S %Y %z",
+        "iso" | "iso8601" => "%Y-%m-%d %H:%M:%S %z",
+        "iso-strict" | "iso8601-strict" => "%Y-%m-%dT%H:%M:%S%:z",
+        "rfc" | "rfc2822" => "%a, %-d %b %Y %H:%M:%S %z",
+        "short" => "%Y-%m-%d",
+        "raw" => "%s %z",
+        "unix" => "%s",
+        _ => return None,
+    };
+
+    Some(format.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn read(contents: &str) -> ConfigFile {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config");
+        fs::write(&path, contents).unwrap();
+
+        let config = git2::Config::open(&path).unwrap();
+        GitConfigReader::read(&config)
+    }
+
+    #[test]
+    fn test_read_empty_config() {
+        assert_eq!(read(""), ConfigFile::default());
+    }
+
+    #[test]
+    fn test_read_git_config() {
+        let config = read(
+            "[diff]\n\trenames = copies\n\trenameLimit = 2000\n\
+             [core]\n\tabbrev = 12\n\
+             [log]\n\tdate = short\n\
+             [i18n]\n\tlogOutputEncoding = ISO-8859-1\n\
+             [gpg \"ssh\"]\n\tallowedSignersFile = /etc/ssh/allowed_signers\n",
+        );
+
+        assert_eq!(
+            config.renames,
+            Some(RenamesConfig {
+                enabled: Some(true),
+                threshold: None,
+                limit: Some(2000),
+            })
+        );
+        assert_eq!(config.abbrev, Some(12));
+        assert_eq!(config.date_format.as_deref(), Some("%Y-%m-%d"));
+        assert_eq!(config.encoding.as_deref(), Some("ISO-8859-1"));
+        assert_eq!(
+            config.signing.and_then(|signing| signing.allowed_signers),
+            Some("/etc/ssh/allowed_signers".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_abbrev() {
+        assert_eq!(parse_abbrev("auto"), Some(7));
+        assert_eq!(parse_abbrev("no"), Some(40));
+        assert_eq!(parse_abbrev("10"), Some(10));
+        assert_eq!(parse_abbrev("many"), None);
+    }
+
+    #[test]
+    fn test_date_format() {
+        assert_eq!(date_format("format:%d/%m/%Y").as_deref(), Some("%d/%m/%Y"));
+        assert_eq!(
+            date_format("auto:iso").as_deref(),
+            Some("%Y-%m-%d %H:%M:%S %z")
+        );
+        assert_eq!(date_format("relative"), None);
+        assert_eq!(date_format("format-local:%H:%M"), None);
+    }
+}
@@ -0,0 +1,7 @@
+pub mod config_file;
+pub mod config_loader;
+pub mod git_config;
+
+pub use config_file::{ConfigFile, LintConfig, RenamesConfig, SigningConfig};
+pub use config_loader::{ConfigError, ConfigLoader, PROJECT_CONFIG_FILE};
+pub use git_config::GitConfigReader;
@@ -1,3 +1,5 @@
 pub mod adapters;
+pub mod config;
 pub mod output;
 pub mod repositories;
+pub mod signing;
@@ -1,6 +1,16 @@
-use crate::application::dto::{CommitDto, FileDto};
+use crate::application::dto::{
+    AuthorDto, ChangelogOutputDto, CommitDto, DirStatDto, FileDto, IssueDto, LintDto, OutputDto,
+    OwnerGroupDto, PackageDto,
+};
 
 pub trait OutputFormatter {
-    fn format_commits(&self, commits: &[CommitDto]) -> Result<(), String>;
-    fn format_files(&self, files: &[FileDto]) -> Result<(), String>;
+    fn format_commits(&self, output: &OutputDto<CommitDto>) -> Result<(), String>;
+    fn format_files(&self, output: &OutputDto<FileDto>) -> Result<(), String>;
+    fn format_dirstat(&self, output: &OutputDto<DirStatDto>) -> Result<(), String>;
+    fn format_authors(&self, output: &OutputDto<AuthorDto>) -> Result<(), String>;
+    fn format_owners(&self, output: &OutputDto<OwnerGroupDto>) -> Result<(), String>;
+    fn format_packages(&self, output: &OutputDto<PackageDto>) -> Result<(), String>;
+    fn format_changelog(&self, output: &ChangelogOutputDto) -> Result<(), String>;
+    fn format_issues(&self, output: &OutputDto<IssueDto>) -> Result<(), String>;
+    fn format_lint(&self, output: &OutputDto<LintDto>) -> Result<(), String>;
 }
@@ -1,28 +1,93 @@
-use crate::application::dto::{CommitDto, FileDto};
+use crate::application::dto::{
+    AuthorDto, ChangelogOutputDto, CommitDto, DirStatDto, ErrorOutputDto, FileDto, IssueDto,
+    LintDto, OutputDto, OwnerGroupDto, PackageDto,
+};
 use crate::infra::output::OutputFormatter;
 
 pub struct JsonFormatter;
 
+impl JsonFormatter {
+    pub fn format_error(&self, output: &ErrorOutputDto) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize error to JSON: {}", e))?;
+        eprintln!("{}", json);
+        Ok(())
+    }
+}
+
 impl OutputFormatter for JsonFormatter {
-    fn format_commits(&self, commits: &[CommitDto]) -> Result<(), String> {
-        let json = serde_json::to_string_pretty(commits)
+    fn format_commits(&self, output: &OutputDto<CommitDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
             .map_err(|e| format!("Failed to serialize commits to JSON: {}", e))?;
         println!("{}", json);
         Ok(())
     }
 
-    fn format_files(&self, files: &[FileDto]) -> Result<(), String> {
-        let json = serde_json::to_string_pretty(files)
+    fn format_files(&self, output: &OutputDto<FileDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
             .map_err(|e| format!("Failed to serialize files to JSON: {}", e))?;
         println!("{}", json);
         Ok(())
     }
+
+    fn format_dirstat(&self, output: &OutputDto<DirStatDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize directory stats to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_authors(&self, output: &OutputDto<AuthorDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize authors to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_owners(&self, output: &OutputDto<OwnerGroupDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize owner groups to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_packages(&self, output: &OutputDto<PackageDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize packages to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_changelog(&self, output: &ChangelogOutputDto) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize changelog to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_issues(&self, output: &OutputDto<IssueDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize issues to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    fn format_lint(&self, output: &OutputDto<LintDto>) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(output)
+            .map_err(|e| format!("Failed to serialize lint results to JSON: {}", e))?;
+        println!("{}", json);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::dto::{CommitDto, FileDto};
+    use crate::application::dto::{CommitDto, ErrorDto, FileDto};
+
+    fn wrap<T>(items: Vec<T>) -> OutputDto<T> {
+        OutputDto::new("main".to_string(), "HEAD".to_string(), items)
+    }
 
     #[test]
     fn test_format_commits() {
@@ -33,9 +98,16 @@ mod tests {
             email: "test@example.com".to_string(),
             date: "2021-10-18 12:31:30".to_string(),
             message: "Test commit message".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
         }];
 
-        let result = formatter.format_commits(&commits);
+        let result = formatter.format_commits(&wrap(commits));
         assert!(result.is_ok());
     }
 
@@ -45,23 +117,73 @@ mod tests {
         let files = vec![FileDto {
             path: "src/main.rs".to_string(),
             status: "modified".to_string(),
+            owners: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        }];
+
+        let result = formatter.format_files(&wrap(files));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_format_error() {
+        let formatter = JsonFormatter;
+        let output = ErrorOutputDto::from(ErrorDto::new(
+            "branch_not_found",
+            Some("main".to_string()),
+            "Branch not found: main".to_string(),
+        ));
+
+        let result = formatter.format_error(&output);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_format_dirstat() {
+        let formatter = JsonFormatter;
+        let directories = vec![DirStatDto {
+            directory: "src/".to_string(),
+            files: 3,
+            lines_added: 20,
+            lines_removed: 5,
+            percentage: 100.0,
+        }];
+
+        let result = formatter.format_dirstat(&wrap(directories));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_format_authors() {
+        let formatter = JsonFormatter;
+        let authors = vec![AuthorDto {
+            name: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            commits: 2,
+            first_commit_date: "2021-10-18 12:31:30".to_string(),
+            last_commit_date: "2021-10-19 08:00:00".to_string(),
+            lines_added: None,
+            lines_removed: None,
         }];
 
-        let result = formatter.format_files(&files);
+        let result = formatter.format_authors(&wrap(authors));
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_empty_commits() {
         let formatter = JsonFormatter;
-        let result = formatter.format_commits(&[]);
+        let result = formatter.format_commits(&wrap(vec![]));
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_empty_files() {
         let formatter = JsonFormatter;
-        let result = formatter.format_files(&[]);
+        let result = formatter.format_files(&wrap(vec![]));
         assert!(result.is_ok());
     }
 }
@@ -1,67 +1,868 @@
-use crate::application::dto::{CommitDto, FileDto};
+use crate::application::dto::{
+    AuthorDto, ChangelogOutputDto, CommitDto, DirStatDto, FileDto, IssueDto, LintDto, OutputDto,
+    OwnerGroupDto, PackageDto,
+};
+use crate::domain::entities::DiffLineKind;
+use crate::domain::services::WordDiffDomainService;
+use crate::domain::value_objects::TextEncoding;
 use crate::infra::output::OutputFormatter;
+use std::io::{self, Write};
 
-pub struct SimpleFormatter;
+/// Hash length in changelogs when no `abbrev` is set.
+const CHANGELOG_ABBREV: usize = 7;
 
-impl OutputFormatter for SimpleFormatter {
-    fn format_commits(&self, commits: &[CommitDto]) -> Result<(), String> {
-        for commit in commits {
-            println!("{} {} {}", commit.hash, commit.date, commit.message);
+/// Hash length of submodule pointers, and of submodule commits when no
+/// `abbrev` is set, as in `git diff --submodule=log`.
+const SUBMODULE_ABBREV: usize = 7;
+
+#[derive(Debug, Default)]
+pub struct SimpleFormatter {
+    abbrev: Option<usize>,
+    encoding: TextEncoding,
+    signatures: bool,
+    word_diff: bool,
+}
+
+impl SimpleFormatter {
+    /// Shortens commit hashes to `abbrev` characters.
+    pub fn with_abbrev(mut self, abbrev: Option<usize>) -> Self {
+        self.abbrev = abbrev;
+        self
+    }
+
+    /// Writes output in `encoding` instead of UTF-8.
+    pub fn with_encoding(mut self, encoding: TextEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Appends each commit's signature status, e.g. ` [good]`.
+    pub fn with_signatures(mut self, signatures: bool) -> Self {
+        self.signatures = signatures;
+        self
+    }
+
+    /// Shows patches as `git diff --word-diff` does.
+    pub fn with_word_diff(mut self, word_diff: bool) -> Self {
+        self.word_diff = word_diff;
+        self
+    }
+
+    fn commit_line(&self, commit: &CommitDto) -> String {
+        let hash = abbreviate(&commit.hash, self.abbrev);
+        let mut line = format!("{} {} {}", hash, commit.date, commit.message);
+        if !commit.missing_trailers.is_empty() {
+            line.push_str(&format!(
+                " (missing {})",
+                commit.missing_trailers.join(", ")
+            ));
         }
-        Ok(())
+        if self.signatures {
+            line.push_str(&format!(" [{}]", commit.signature));
+        }
+        line
+    }
+
+    /// The changelog as Markdown, one `###` heading per section.
+    fn changelog_lines(&self, output: &ChangelogOutputDto) -> Vec<String> {
+        let abbrev = self.abbrev.unwrap_or(CHANGELOG_ABBREV);
+        let mut lines = Vec::new();
+
+        for section in &output.changelog.sections {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!("### {}", section.title));
+            lines.push(String::new());
+
+            for entry in &section.entries {
+                let description = entry
+                    .description
+                    .lines()
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+                    .join("\n  ");
+                let hash = abbreviate(&entry.hash, Some(abbrev));
+                lines.push(match &entry.scope {
+                    Some(scope) => format!("- **{}:** {} ({})", scope, description, hash),
+                    None => format!("- {} ({})", description, hash),
+                });
+            }
+        }
+        lines
+    }
+
+    fn file_line(file: &FileDto) -> String {
+        let mut line = format!("{} {}", file.status, file.path);
+        for owner in file.owners.iter().flatten() {
+            line.push(' ');
+            line.push_str(owner);
+        }
+        if let Some(submodule) = &file.submodule {
+            let pointers: Vec<&str> = [&submodule.old_commit, &submodule.new_commit]
+                .into_iter()
+                .flatten()
+                .map(|hash| abbreviate(hash, Some(SUBMODULE_ABBREV)))
+                .collect();
+            line.push_str(&format!(" (submodule {})", pointers.join("..")));
+        }
+        if file.generated {
+            line.push_str(" (generated)");
+        }
+        if file.whitespace_only {
+            line.push_str(" (whitespace only)");
+        }
+        line
+    }
+
+    fn owner_group_lines(group: &OwnerGroupDto) -> impl Iterator<Item = String> + '_ {
+        let heading = group.owner.as_deref().unwrap_or("(unowned)").to_string();
+        std::iter::once(heading).chain(
+            group
+                .files
+                .iter()
+                .map(|file| format!("  {}", Self::file_line(file))),
+        )
+    }
+
+    fn issue_lines<'a>(&'a self, issue: &'a IssueDto) -> impl Iterator<Item = String> + 'a {
+        std::iter::once(issue.issue.clone()).chain(
+            issue
+                .commits
+                .iter()
+                .map(|commit| format!("  {}", self.commit_line(commit))),
+        )
+    }
+
+    /// The commit line followed by its changed files, indented, or by
+    /// their patches.
+    fn commit_lines<'a>(&'a self, commit: &'a CommitDto) -> impl Iterator<Item = String> + 'a {
+        std::iter::once(self.commit_line(commit)).chain(commit.files.iter().flatten().flat_map(
+            |file| match &file.patch {
+                Some(_) => self.patch_lines(file),
+                None => vec![format!("  {}", Self::file_line(file))],
+            },
+        ))
+    }
+
+    /// The file line, or the file's patch, followed by the submodule
+    /// commits between its old and new pointers, indented.
+    fn file_lines(&self, file: &FileDto) -> Vec<String> {
+        let mut lines = self.patch_lines(file);
+        let commits = file
+            .submodule
+            .iter()
+            .flat_map(|submodule| submodule.commits.iter().flatten());
+        let abbrev = self.abbrev.unwrap_or(SUBMODULE_ABBREV);
+        lines.extend(commits.map(|commit| {
+            let hash = abbreviate(&commit.hash, Some(abbrev));
+            format!("  {} {} {}", hash, commit.date, commit.message)
+        }));
+        lines
+    }
+
+    /// The file's patch as a unified diff, or just the file line when it
+    /// has no patch.
+    fn patch_lines(&self, file: &FileDto) -> Vec<String> {
+        let Some(patch) = &file.patch else {
+            return vec![Self::file_line(file)];
+        };
+
+        let old_path = patch.old_path.as_deref().unwrap_or(&file.path);
+        let old_name = match file.status.as_str() {
+            "added" => "/dev/null".to_string(),
+            _ => format!("a/{}", old_path),
+        };
+        let new_name = match file.status.as_str() {
+            "deleted" => "/dev/null".to_string(),
+            _ => format!("b/{}", file.path),
+        };
+
+        let mut lines = vec![format!("diff --git a/{} b/{}", old_path, file.path)];
+        // The mode lines let `git apply` create and delete files.
+        match (&patch.old_mode, &patch.new_mode) {
+            (None, Some(new_mode)) => lines.push(format!("new file mode {}", new_mode)),
+            (Some(old_mode), None) => lines.push(format!("deleted file mode {}", old_mode)),
+            (Some(old_mode), Some(new_mode)) if old_mode != new_mode => {
+                lines.push(format!("old mode {}", old_mode));
+                lines.push(format!("new mode {}", new_mode));
+            }
+            _ => {}
+        }
+        if patch.binary {
+            lines.push(format!("Binary files {} and {} differ", old_name, new_name));
+            return lines;
+        }
+        if patch.hunks.is_empty() {
+            return lines;
+        }
+
+        lines.push(format!("--- {}", old_name));
+        lines.push(format!("+++ {}", new_name));
+        for hunk in &patch.hunks {
+            let range = format!(
+                "@@ -{} +{} @@",
+                hunk_range(hunk.old_start, hunk.old_lines),
+                hunk_range(hunk.new_start, hunk.new_lines)
+            );
+            lines.push(match hunk.header.as_str() {
+                "" => range,
+                header => format!("{} {}", range, header),
+            });
+
+            let hunk_lines = hunk.lines.iter().map(|line| {
+                let kind = DiffLineKind::from_name(&line.kind).unwrap_or(DiffLineKind::Context);
+                (kind, line.content.as_str())
+            });
+            if self.word_diff {
+                lines.extend(WordDiffDomainService::render(hunk_lines));
+            } else {
+                lines.extend(
+                    hunk_lines.map(|(kind, content)| format!("{}{}", kind.origin(), content)),
+                );
+            }
+        }
+        lines
+    }
+
+    fn lint_lines<'a>(&'a self, lint: &'a LintDto) -> impl Iterator<Item = String> + 'a {
+        std::iter::once(self.commit_line(&lint.commit)).chain(
+            lint.violations
+                .iter()
+                .map(|violation| format!("  {}: {}", violation.rule, violation.message)),
+        )
+    }
+
+    fn dirstat_line(directory: &DirStatDto) -> String {
+        let unit = if directory.files == 1 {
+            "file"
+        } else {
+            "files"
+        };
+        format!(
+            "{:5.1}% {} ({} {})",
+            directory.percentage, directory.directory, directory.files, unit
+        )
     }
 
-    fn format_files(&self, files: &[FileDto]) -> Result<(), String> {
-        for file in files {
-            println!("{} {}", file.status, file.path);
+    fn author_line(author: &AuthorDto) -> String {
+        let mut line = format!(
+            "{:>6}\t{} <{}>\t{}\t{}",
+            author.commits,
+            author.name,
+            author.email,
+            author.first_commit_date,
+            author.last_commit_date
+        );
+        if let (Some(added), Some(removed)) = (author.lines_added, author.lines_removed) {
+            line.push_str(&format!("\t+{} -{}", added, removed));
+        }
+        line
+    }
+
+    fn write_lines(&self, lines: impl Iterator<Item = String>) -> Result<(), String> {
+        let mut stdout = io::stdout().lock();
+        for line in lines {
+            stdout
+                .write_all(&self.encoding.encode(&line))
+                .and_then(|()| stdout.write_all(b"\n"))
+                .map_err(|e| e.to_string())?;
         }
         Ok(())
     }
 }
 
+impl OutputFormatter for SimpleFormatter {
+    fn format_commits(&self, output: &OutputDto<CommitDto>) -> Result<(), String> {
+        self.write_lines(
+            output
+                .items
+                .iter()
+                .flat_map(|commit| self.commit_lines(commit)),
+        )
+    }
+
+    fn format_files(&self, output: &OutputDto<FileDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().flat_map(|file| self.file_lines(file)))
+    }
+
+    fn format_dirstat(&self, output: &OutputDto<DirStatDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().map(Self::dirstat_line))
+    }
+
+    fn format_authors(&self, output: &OutputDto<AuthorDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().map(Self::author_line))
+    }
+
+    fn format_owners(&self, output: &OutputDto<OwnerGroupDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().flat_map(Self::owner_group_lines))
+    }
+
+    fn format_packages(&self, output: &OutputDto<PackageDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().map(|package| package.name.clone()))
+    }
+
+    fn format_changelog(&self, output: &ChangelogOutputDto) -> Result<(), String> {
+        self.write_lines(self.changelog_lines(output).into_iter())
+    }
+
+    fn format_issues(&self, output: &OutputDto<IssueDto>) -> Result<(), String> {
+        self.write_lines(
+            output
+                .items
+                .iter()
+                .flat_map(|issue| self.issue_lines(issue)),
+        )
+    }
+
+    fn format_lint(&self, output: &OutputDto<LintDto>) -> Result<(), String> {
+        self.write_lines(output.items.iter().flat_map(|lint| self.lint_lines(lint)))
+    }
+}
+
+/// One side of a hunk header as git writes it: the line count is left out
+/// when it is 1, and an empty side is `start,0`.
+fn hunk_range(start: u32, lines: u32) -> String {
+    match lines {
+        1 => start.to_string(),
+        _ => format!("{},{}", start, lines),
+    }
+}
+
+fn abbreviate(hash: &str, abbrev: Option<usize>) -> &str {
+    match abbrev {
+        Some(abbrev) => hash.get(..abbrev).unwrap_or(hash),
+        None => hash,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::dto::{CommitDto, FileDto};
+    use crate::application::dto::{
+        ChangelogDto, ChangelogEntryDto, ChangelogSectionDto, CommitDto, DiffLineDto, FileDto,
+        HunkDto, LintViolationDto, PatchDto, SubmoduleDto,
+    };
+
+    fn wrap<T>(items: Vec<T>) -> OutputDto<T> {
+        OutputDto::new("main".to_string(), "HEAD".to_string(), items)
+    }
 
     #[test]
     fn test_format_commits() {
-        let formatter = SimpleFormatter;
+        let formatter = SimpleFormatter::default();
         let commits = vec![CommitDto {
             hash: "abcdef12".to_string(),
             author: "Test Author".to_string(),
             email: "test@example.com".to_string(),
             date: "2021-10-18 12:31:30".to_string(),
             message: "Test commit message".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
         }];
 
-        let result = formatter.format_commits(&commits);
+        let result = formatter.format_commits(&wrap(commits));
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_files() {
-        let formatter = SimpleFormatter;
+        let formatter = SimpleFormatter::default();
         let files = vec![FileDto {
             path: "src/main.rs".to_string(),
             status: "modified".to_string(),
+            owners: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
         }];
 
-        let result = formatter.format_files(&files);
+        let result = formatter.format_files(&wrap(files));
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_empty_commits() {
-        let formatter = SimpleFormatter;
-        let result = formatter.format_commits(&[]);
+        let formatter = SimpleFormatter::default();
+        let result = formatter.format_commits(&wrap(vec![]));
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_format_empty_files() {
-        let formatter = SimpleFormatter;
-        let result = formatter.format_files(&[]);
+        let formatter = SimpleFormatter::default();
+        let result = formatter.format_files(&wrap(vec![]));
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_file_line_with_owners() {
+        let file = FileDto {
+            path: "src/main.rs".to_string(),
+            status: "modified".to_string(),
+            owners: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        };
+        assert_eq!(SimpleFormatter::file_line(&file), "modified src/main.rs");
+
+        let file = file.with_owners(vec!["@backend".to_string(), "@leads".to_string()]);
+        assert_eq!(
+            SimpleFormatter::file_line(&file),
+            "modified src/main.rs @backend @leads"
+        );
+
+        let file = FileDto {
+            whitespace_only: true,
+            generated: true,
+            ..file
+        };
+        assert_eq!(
+            SimpleFormatter::file_line(&file),
+            "modified src/main.rs @backend @leads (generated) (whitespace only)"
+        );
+    }
+
+    #[test]
+    fn test_owner_group_lines() {
+        let group = OwnerGroupDto {
+            owner: None,
+            files: vec![FileDto {
+                path: "README.md".to_string(),
+                status: "added".to_string(),
+                owners: None,
+                patch: None,
+                whitespace_only: false,
+                generated: false,
+                submodule: None,
+            }],
+        };
+
+        let lines: Vec<String> = SimpleFormatter::owner_group_lines(&group).collect();
+        assert_eq!(lines, vec!["(unowned)", "  added README.md"]);
+    }
+
+    #[test]
+    fn test_issue_lines() {
+        let issue = IssueDto {
+            issue: "PROJ-12".to_string(),
+            commits: vec![CommitDto {
+                hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+                author: "Test Author".to_string(),
+                email: "test@example.com".to_string(),
+                date: "2021-10-18".to_string(),
+                message: "PROJ-12: fix login".to_string(),
+                issues: vec!["PROJ-12".to_string()],
+                trailers: vec![],
+                missing_trailers: vec![],
+                warnings: vec![],
+                signature: "unsigned".to_string(),
+                signature_format: None,
+                files: None,
+            }],
+        };
+
+        let formatter = SimpleFormatter::default().with_abbrev(Some(7));
+        let lines: Vec<String> = formatter.issue_lines(&issue).collect();
+        assert_eq!(
+            lines,
+            vec!["PROJ-12", "  abcdef1 2021-10-18 PROJ-12: fix login"]
+        );
+    }
+
+    #[test]
+    fn test_lint_lines() {
+        let lint = LintDto {
+            commit: CommitDto {
+                hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+                author: "Test Author".to_string(),
+                email: "test@example.com".to_string(),
+                date: "2021-10-18".to_string(),
+                message: "Fixed it.".to_string(),
+                issues: vec![],
+                trailers: vec![],
+                missing_trailers: vec![],
+                warnings: vec![],
+                signature: "unsigned".to_string(),
+                signature_format: None,
+                files: None,
+            },
+            violations: vec![LintViolationDto {
+                rule: "trailing-period".to_string(),
+                message: "Subject ends with a period".to_string(),
+            }],
+        };
+
+        let formatter = SimpleFormatter::default().with_abbrev(Some(7));
+        let lines: Vec<String> = formatter.lint_lines(&lint).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "abcdef1 2021-10-18 Fixed it.",
+                "  trailing-period: Subject ends with a period",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changelog_lines() {
+        let output = ChangelogOutputDto::new(
+            "v1.0.0".to_string(),
+            "HEAD".to_string(),
+            ChangelogDto {
+                bump: "major".to_string(),
+                next_version: Some("v2.0.0".to_string()),
+                sections: vec![
+                    ChangelogSectionDto {
+                        title: "Breaking Changes".to_string(),
+                        entries: vec![ChangelogEntryDto {
+                            hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+                            scope: None,
+                            description: "`--base` is gone\n  pass the branch instead".to_string(),
+                        }],
+                    },
+                    ChangelogSectionDto {
+                        title: "Features".to_string(),
+                        entries: vec![ChangelogEntryDto {
+                            hash: "1234567890abcdef1234567890abcdef12345678".to_string(),
+                            scope: Some("cli".to_string()),
+                            description: "add changelog".to_string(),
+                        }],
+                    },
+                ],
+            },
+        );
+
+        assert_eq!(
+            SimpleFormatter::default().changelog_lines(&output),
+            vec![
+                "### Breaking Changes",
+                "",
+                "- `--base` is gone\n  pass the branch instead (abcdef1)",
+                "",
+                "### Features",
+                "",
+                "- **cli:** add changelog (1234567)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_patch_lines() {
+        let line = |kind: &str, content: &str| DiffLineDto {
+            kind: kind.to_string(),
+            content: content.to_string(),
+        };
+        let file = FileDto {
+            path: "src/parser.rs".to_string(),
+            status: "renamed".to_string(),
+            owners: None,
+            patch: Some(PatchDto {
+                old_path: Some("src/lexer.rs".to_string()),
+                old_mode: Some("100644".to_string()),
+                new_mode: Some("100644".to_string()),
+                binary: false,
+                hunks: vec![HunkDto {
+                    old_start: 1,
+                    old_lines: 2,
+                    new_start: 1,
+                    new_lines: 2,
+                    header: "mod lexer".to_string(),
+                    lines: vec![
+                        line("context", "use std::io;"),
+                        line("removed", "pub fn lex() {}"),
+                        line("added", "pub fn parse() {}"),
+                    ],
+                }],
+            }),
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        };
+
+        let formatter = SimpleFormatter::default();
+        assert_eq!(
+            formatter.patch_lines(&file),
+            vec![
+                "diff --git a/src/lexer.rs b/src/parser.rs",
+                "--- a/src/lexer.rs",
+                "+++ b/src/parser.rs",
+                "@@ -1,2 +1,2 @@ mod lexer",
+                " use std::io;",
+                "-pub fn lex() {}",
+                "+pub fn parse() {}",
+            ]
+        );
+
+        let added = FileDto {
+            path: "VERSION".to_string(),
+            status: "added".to_string(),
+            owners: None,
+            patch: Some(PatchDto {
+                old_path: None,
+                old_mode: None,
+                new_mode: Some("100644".to_string()),
+                binary: false,
+                hunks: vec![HunkDto {
+                    old_start: 0,
+                    old_lines: 0,
+                    new_start: 1,
+                    new_lines: 1,
+                    header: String::new(),
+                    lines: vec![line("added", "1.0.0")],
+                }],
+            }),
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        };
+        assert_eq!(
+            formatter.patch_lines(&added),
+            vec![
+                "diff --git a/VERSION b/VERSION",
+                "new file mode 100644",
+                "--- /dev/null",
+                "+++ b/VERSION",
+                "@@ -0,0 +1 @@",
+                "+1.0.0",
+            ]
+        );
+
+        let formatter = formatter.with_word_diff(true);
+        assert_eq!(
+            formatter.patch_lines(&file)[4..],
+            ["use std::io;", "pub fn [-lex()-]{+parse()+} {}"]
+        );
+
+        let binary = FileDto {
+            path: "logo.png".to_string(),
+            status: "added".to_string(),
+            owners: None,
+            patch: Some(PatchDto {
+                old_path: None,
+                old_mode: None,
+                new_mode: Some("100644".to_string()),
+                binary: true,
+                hunks: vec![],
+            }),
+            whitespace_only: false,
+            generated: false,
+            submodule: None,
+        };
+        assert_eq!(
+            formatter.patch_lines(&binary),
+            vec![
+                "diff --git a/logo.png b/logo.png",
+                "new file mode 100644",
+                "Binary files /dev/null and b/logo.png differ",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_lines_with_files() {
+        let commit = CommitDto {
+            hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+            author: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            date: "2021-10-18".to_string(),
+            message: "Rename parser".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: Some(vec![
+                FileDto {
+                    path: "src/parser.rs".to_string(),
+                    status: "renamed".to_string(),
+                    owners: None,
+                    patch: None,
+                    whitespace_only: false,
+                    generated: false,
+                    submodule: None,
+                },
+                FileDto {
+                    path: "src/lib.rs".to_string(),
+                    status: "modified".to_string(),
+                    owners: None,
+                    patch: None,
+                    whitespace_only: false,
+                    generated: false,
+                    submodule: None,
+                },
+            ]),
+        };
+
+        let formatter = SimpleFormatter::default().with_abbrev(Some(7));
+        assert_eq!(
+            formatter.commit_lines(&commit).collect::<Vec<_>>(),
+            vec![
+                "abcdef1 2021-10-18 Rename parser",
+                "  renamed src/parser.rs",
+                "  modified src/lib.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_lines_with_submodule() {
+        let commit = CommitDto {
+            hash: "fedcba9876543210fedcba9876543210fedcba98".to_string(),
+            author: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            date: "2021-10-18".to_string(),
+            message: "Fix parser".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
+        };
+        let file = FileDto {
+            path: "vendor/parser".to_string(),
+            status: "modified".to_string(),
+            owners: None,
+            patch: None,
+            whitespace_only: false,
+            generated: false,
+            submodule: Some(SubmoduleDto {
+                old_commit: Some("abcdef1234567890abcdef1234567890abcdef12".to_string()),
+                new_commit: Some(commit.hash.clone()),
+                commits: Some(vec![commit]),
+            }),
+        };
+
+        let formatter = SimpleFormatter::default();
+        assert_eq!(
+            formatter.file_lines(&file),
+            vec![
+                "modified vendor/parser (submodule abcdef1..fedcba9)",
+                "  fedcba9 2021-10-18 Fix parser",
+            ]
+        );
+
+        let added = FileDto {
+            status: "added".to_string(),
+            submodule: Some(SubmoduleDto {
+                old_commit: None,
+                new_commit: Some("fedcba9876543210fedcba9876543210fedcba98".to_string()),
+                commits: None,
+            }),
+            ..file
+        };
+        assert_eq!(
+            formatter.file_lines(&added),
+            vec!["added vendor/parser (submodule fedcba9)"]
+        );
+    }
+
+    #[test]
+    fn test_dirstat_line() {
+        let directory = DirStatDto {
+            directory: "core/src/".to_string(),
+            files: 1,
+            lines_added: 3,
+            lines_removed: 1,
+            percentage: 8.25,
+        };
+
+        assert_eq!(
+            SimpleFormatter::dirstat_line(&directory),
+            "  8.2% core/src/ (1 file)"
+        );
+    }
+
+    #[test]
+    fn test_author_line() {
+        let mut author = AuthorDto {
+            name: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            commits: 12,
+            first_commit_date: "2021-10-18".to_string(),
+            last_commit_date: "2021-10-19".to_string(),
+            lines_added: None,
+            lines_removed: None,
+        };
+
+        assert_eq!(
+            SimpleFormatter::author_line(&author),
+            "    12\tTest Author <test@example.com>\t2021-10-18\t2021-10-19"
+        );
+
+        author.lines_added = Some(40);
+        author.lines_removed = Some(7);
+        assert!(SimpleFormatter::author_line(&author).ends_with("\t+40 -7"));
+    }
+
+    #[test]
+    fn test_commit_line_abbrev() {
+        let commit = CommitDto {
+            hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+            author: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            date: "2021-10-18".to_string(),
+            message: "Test commit message".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec![],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
+        };
+
+        let formatter = SimpleFormatter::default();
+        assert_eq!(
+            formatter.commit_line(&commit),
+            "abcdef1234567890abcdef1234567890abcdef12 2021-10-18 Test commit message"
+        );
+
+        let formatter = SimpleFormatter::default().with_abbrev(Some(7));
+        assert_eq!(
+            formatter.commit_line(&commit),
+            "abcdef1 2021-10-18 Test commit message"
+        );
+    }
+
+    #[test]
+    fn test_commit_line_missing_trailers() {
+        let commit = CommitDto {
+            hash: "abcdef1234567890abcdef1234567890abcdef12".to_string(),
+            author: "Test Author".to_string(),
+            email: "test@example.com".to_string(),
+            date: "2021-10-18".to_string(),
+            message: "Test commit message".to_string(),
+            issues: vec![],
+            trailers: vec![],
+            missing_trailers: vec!["Signed-off-by".to_string(), "Reviewed-by".to_string()],
+            warnings: vec![],
+            signature: "unsigned".to_string(),
+            signature_format: None,
+            files: None,
+        };
+
+        let formatter = SimpleFormatter::default().with_abbrev(Some(7));
+        assert_eq!(
+            formatter.commit_line(&commit),
+            "abcdef1 2021-10-18 Test commit message (missing Signed-off-by, Reviewed-by)"
+        );
+
+        let formatter = formatter.with_signatures(true);
+        assert_eq!(
+            formatter.commit_line(&commit),
+            "abcdef1 2021-10-18 Test commit message (missing Signed-off-by, Reviewed-by) [unsigned]"
+        );
+    }
 }
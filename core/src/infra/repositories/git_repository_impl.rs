@@ -1,8 +1,20 @@
-use crate::domain::entities::{Commit, FileChange, FileChangeStatus};
+use crate::domain::entities::{
+    BaseBranchCandidates, Commit, DiffLine, DiffLineKind, FileChange, FileChangeStatus, FilePatch,
+    Hunk, SubmoduleChange,
+};
 use crate::domain::repositories::{GitRepository, GitRepositoryError};
-use crate::domain::value_objects::{BranchName, CommitHash};
+use crate::domain::services::BranchSuggestionDomainService;
+use crate::domain::value_objects::{
+    BranchName, CommitHash, ComparisonEndpoints, FileComparisonOptions, FilePath, LineStats,
+    RenameDetection, SignatureFormat, SignatureStatus, SignatureVerification, TextEncoding,
+};
 use crate::infra::adapters::FilePathConverter;
-use git2::{Oid, Repository};
+use crate::infra::config::{ConfigFile, GitConfigReader};
+use crate::infra::signing::SignatureVerifier;
+use git2::{BranchType, Oid, Repository};
+
+const LOCAL_DEFAULT_BRANCHES: [&str; 2] = ["main", "master"];
+const REMOTE_DEFAULT_BRANCHES: [&str; 2] = ["origin/main", "origin/master"];
 
 pub struct GitRepositoryImpl {
     repo: Repository,
@@ -10,8 +22,11 @@ pub struct GitRepositoryImpl {
 
 impl GitRepositoryImpl {
     pub fn open(path: &str) -> Result<Self, GitRepositoryError> {
-        let repo = Repository::open(path).map_err(|e| GitRepositoryError::GitOperationFailed {
-            message: format!("Failed to open repository: {}", e),
+        let repo = Repository::open(path).map_err(|e| match e.code() {
+            git2::ErrorCode::NotFound => GitRepositoryError::RepositoryNotFound,
+            _ => GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to open repository: {}", e),
+            },
         })?;
 
         Ok(Self { repo })
@@ -20,47 +35,83 @@ impl GitRepositoryImpl {
     pub fn open_current_dir() -> Result<Self, GitRepositoryError> {
         Self::open(".")
     }
+
+    pub fn workdir(&self) -> Option<&std::path::Path> {
+        self.repo.workdir()
+    }
+
+    /// Git configuration keys with an arlon equivalent, as a config layer.
+    pub fn git_config(&self) -> ConfigFile {
+        self.repo
+            .config()
+            .map(|config| GitConfigReader::read(&config))
+            .unwrap_or_default()
+    }
+
+    /// Short names of the local branches, remote branches and tags that
+    /// point at a commit: the names `find_branch_commit` resolves.
+    pub fn ref_names(&self) -> Vec<String> {
+        let Ok(references) = self.repo.references() else {
+            return Vec::new();
+        };
+
+        references
+            .flatten()
+            .filter(|reference| {
+                reference.is_branch() || reference.is_remote() || reference.is_tag()
+            })
+            .filter(|reference| reference.peel_to_commit().is_ok())
+            .filter_map(|reference| reference.shorthand().map(str::to_string))
+            .filter(|name| !name.ends_with("/HEAD"))
+            .collect()
+    }
 }
 
 impl GitRepository for GitRepositoryImpl {
-    fn get_commits_from_head(&self) -> Result<Vec<Commit>, GitRepositoryError> {
-        let head = self
-            .repo
-            .head()
-            .map_err(|e| GitRepositoryError::GitOperationFailed {
-                message: format!("Failed to get HEAD: {}", e),
-            })?;
-        let head_commit =
-            head.peel_to_commit()
-                .map_err(|e| GitRepositoryError::GitOperationFailed {
-                    message: format!("Failed to get HEAD commit: {}", e),
-                })?;
+    fn get_commits_not_in_branch(
+        &self,
+        branch: &BranchName,
+        target: Option<BranchName>,
+    ) -> Result<Vec<Commit>, GitRepositoryError> {
+        let target_commit = self.find_target_commit(target.as_ref())?;
+        let branch_commit = self.find_branch_commit(branch)?;
 
-        self.get_commits_from_oid(head_commit.id())
+        self.get_commits_from_oid(target_commit.id(), Some(branch_commit.id()))
     }
 
-    fn get_commits_from_branch(
+    fn resolve_endpoints(
         &self,
         branch: &BranchName,
-    ) -> Result<Vec<Commit>, GitRepositoryError> {
-        let branch_ref = self
-            .repo
-            .find_branch(branch.as_str(), git2::BranchType::Local)
-            .map_err(|_| GitRepositoryError::BranchNotFound {
-                branch: branch.to_string(),
-            })?;
-        let branch_commit = branch_ref.get().peel_to_commit().map_err(|e| {
-            GitRepositoryError::GitOperationFailed {
-                message: format!("Failed to get branch commit: {}", e),
+        target: Option<BranchName>,
+    ) -> Result<ComparisonEndpoints, GitRepositoryError> {
+        let target = self.find_target_commit(target.as_ref())?.id();
+        let base = self.find_branch_commit(branch)?.id();
+        let merge_base = match self.repo.merge_base(base, target) {
+            Ok(oid) => Some(oid),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => None,
+            Err(e) => {
+                return Err(GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to find merge base: {}", e),
+                })
             }
-        })?;
+        };
+        let hash = |oid: Oid| {
+            CommitHash::new(oid.to_string()).map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Invalid commit hash: {}", e),
+            })
+        };
 
-        self.get_commits_from_oid(branch_commit.id())
+        Ok(ComparisonEndpoints {
+            base: hash(base)?,
+            target: hash(target)?,
+            merge_base: merge_base.map(hash).transpose()?,
+        })
     }
 
     fn get_file_changes_between_branches(
         &self,
         branch: &BranchName,
+        options: &FileComparisonOptions,
     ) -> Result<Vec<FileChange>, GitRepositoryError> {
         let head = self
             .repo
@@ -79,17 +130,7 @@ impl GitRepository for GitRepositoryImpl {
                 message: format!("Failed to get HEAD tree: {}", e),
             })?;
 
-        let branch_ref = self
-            .repo
-            .find_branch(branch.as_str(), git2::BranchType::Local)
-            .map_err(|_| GitRepositoryError::BranchNotFound {
-                branch: branch.to_string(),
-            })?;
-        let branch_commit = branch_ref.get().peel_to_commit().map_err(|e| {
-            GitRepositoryError::GitOperationFailed {
-                message: format!("Failed to get branch commit: {}", e),
-            }
-        })?;
+        let branch_commit = self.find_branch_commit(branch)?;
         let branch_tree =
             branch_commit
                 .tree()
@@ -97,75 +138,625 @@ impl GitRepository for GitRepositoryImpl {
                     message: format!("Failed to get branch tree: {}", e),
                 })?;
 
+        self.file_changes_between_trees(Some(&branch_tree), &head_tree, options)
+    }
+
+    fn get_commit_file_changes(
+        &self,
+        commit: &CommitHash,
+        options: &FileComparisonOptions,
+    ) -> Result<Vec<FileChange>, GitRepositoryError> {
+        let oid =
+            Oid::from_str(commit.as_str()).map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Invalid commit hash {}: {}", commit, e),
+            })?;
+        let commit =
+            self.repo
+                .find_commit(oid)
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to find commit: {}", e),
+                })?;
+        let tree = commit
+            .tree()
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to get commit tree: {}", e),
+            })?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => {
+                Some(
+                    parent
+                        .tree()
+                        .map_err(|e| GitRepositoryError::GitOperationFailed {
+                            message: format!("Failed to get parent tree: {}", e),
+                        })?,
+                )
+            }
+            Err(_) => None,
+        };
+
+        self.file_changes_between_trees(parent_tree.as_ref(), &tree, options)
+    }
+
+    fn get_commit_line_stats(&self, commit: &CommitHash) -> Result<LineStats, GitRepositoryError> {
+        let oid =
+            Oid::from_str(commit.as_str()).map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Invalid commit hash {}: {}", commit, e),
+            })?;
+        let commit =
+            self.repo
+                .find_commit(oid)
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to find commit: {}", e),
+                })?;
+
+        if commit.parent_count() > 1 {
+            return Ok(LineStats::default());
+        }
+
+        let tree = commit
+            .tree()
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to get commit tree: {}", e),
+            })?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => {
+                Some(
+                    parent
+                        .tree()
+                        .map_err(|e| GitRepositoryError::GitOperationFailed {
+                            message: format!("Failed to get parent tree: {}", e),
+                        })?,
+                )
+            }
+            Err(_) => None,
+        };
+
+        let stats = self
+            .repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+            .and_then(|diff| diff.stats())
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to compute diff stats: {}", e),
+            })?;
+
+        Ok(LineStats::new(stats.insertions(), stats.deletions()))
+    }
+
+    fn verify_commit_signature(
+        &self,
+        commit: &CommitHash,
+        verification: &SignatureVerification,
+    ) -> Result<SignatureStatus, GitRepositoryError> {
+        let oid =
+            Oid::from_str(commit.as_str()).map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Invalid commit hash {}: {}", commit, e),
+            })?;
+        let Some((signature, payload)) = self.extract_signature(oid)? else {
+            return Ok(SignatureStatus::Unsigned);
+        };
+        let Some(format) = SignatureFormat::detect(&signature) else {
+            return Ok(SignatureStatus::Bad);
+        };
+
+        SignatureVerifier::new(verification)
+            .verify(format, &signature, &payload)
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to verify signature of commit {}: {}", commit, e),
+            })
+    }
+
+    fn get_file_at_head(&self, path: &FilePath) -> Result<Option<String>, GitRepositoryError> {
+        let tree = self
+            .repo
+            .head()
+            .and_then(|head| head.peel_to_tree())
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to get HEAD tree: {}", e),
+            })?;
+
+        self.file_in_tree(&tree, path)
+    }
+
+    fn get_file_at_commit(
+        &self,
+        commit: &CommitHash,
+        path: &FilePath,
+    ) -> Result<Option<String>, GitRepositoryError> {
+        let tree = Oid::from_str(commit.as_str())
+            .and_then(|oid| self.repo.find_commit(oid))
+            .and_then(|commit| commit.tree())
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to get tree of commit {}: {}", commit, e),
+            })?;
+
+        self.file_in_tree(&tree, path)
+    }
+
+    fn get_base_branch_candidates(&self) -> Result<BaseBranchCandidates, GitRepositoryError> {
+        let upstream = self
+            .repo
+            .head()
+            .ok()
+            .filter(|head| head.is_branch())
+            .and_then(|head| head.shorthand().map(str::to_string))
+            .and_then(|name| self.repo.find_branch(&name, BranchType::Local).ok())
+            .and_then(|branch| branch.upstream().ok())
+            .and_then(|upstream| upstream.name().ok().flatten().map(str::to_string))
+            .and_then(|name| BranchName::new(name).ok());
+
+        let remote_default = self
+            .repo
+            .find_reference("refs/remotes/origin/HEAD")
+            .ok()
+            .and_then(|reference| reference.symbolic_target().map(str::to_string))
+            .and_then(|target| target.strip_prefix("refs/remotes/").map(str::to_string))
+            .and_then(|name| BranchName::new(name).ok());
+
+        let local_defaults = LOCAL_DEFAULT_BRANCHES
+            .iter()
+            .filter(|name| self.repo.find_branch(name, BranchType::Local).is_ok())
+            .filter_map(|name| BranchName::new(name.to_string()).ok())
+            .collect();
+
+        let remote_defaults = REMOTE_DEFAULT_BRANCHES
+            .iter()
+            .filter(|name| self.repo.find_branch(name, BranchType::Remote).is_ok())
+            .filter_map(|name| BranchName::new(name.to_string()).ok())
+            .collect();
+
+        Ok(BaseBranchCandidates {
+            upstream,
+            remote_default,
+            local_defaults,
+            remote_defaults,
+        })
+    }
+}
+
+impl GitRepositoryImpl {
+    /// Contents of `path` in `tree`, or `None` if there is no such file.
+    fn file_in_tree(
+        &self,
+        tree: &git2::Tree,
+        path: &FilePath,
+    ) -> Result<Option<String>, GitRepositoryError> {
+        let entry = match tree.get_path(std::path::Path::new(path.as_str())) {
+            Ok(entry) => entry,
+            Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to look up {}: {}", path, e),
+                })
+            }
+        };
+
+        let Ok(blob) = entry
+            .to_object(&self.repo)
+            .and_then(|object| object.peel_to_blob())
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+
+    /// Files that differ from `old_tree` in `new_tree`; a missing
+    /// `old_tree` is the empty tree, as for a root commit.
+    fn file_changes_between_trees(
+        &self,
+        old_tree: Option<&git2::Tree>,
+        new_tree: &git2::Tree,
+        options: &FileComparisonOptions,
+    ) -> Result<Vec<FileChange>, GitRepositoryError> {
         let mut diff_options = git2::DiffOptions::new();
-        let diff = self
+        if let Some(context) = options.patch_context {
+            diff_options.context_lines(context);
+        }
+        let whitespace = options.whitespace;
+        diff_options
+            .ignore_whitespace(whitespace.ignore_all_space)
+            .ignore_whitespace_change(whitespace.ignore_space_change)
+            .ignore_blank_lines(whitespace.ignore_blank_lines);
+        let mut diff = self
             .repo
-            .diff_tree_to_tree(
-                Some(&branch_tree),
-                Some(&head_tree),
-                Some(&mut diff_options),
-            )
+            .diff_tree_to_tree(old_tree, Some(new_tree), Some(&mut diff_options))
             .map_err(|e| GitRepositoryError::GitOperationFailed {
                 message: format!("Failed to create diff: {}", e),
             })?;
 
+        if let RenameDetection::Enabled { threshold } = options.renames {
+            let mut find_options = git2::DiffFindOptions::new();
+            find_options.renames(true).rename_threshold(threshold);
+            if let Some(limit) = options.rename_limit {
+                find_options.rename_limit(limit);
+            }
+            diff.find_similar(Some(&mut find_options)).map_err(|e| {
+                GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to detect renames: {}", e),
+                }
+            })?;
+        }
+
         let mut file_changes = Vec::with_capacity(diff.deltas().len());
 
-        diff.foreach(
-            &mut |delta, _progress| {
-                let status = match delta.status() {
-                    git2::Delta::Unmodified => FileChangeStatus::Unmodified,
-                    git2::Delta::Added => FileChangeStatus::Added,
-                    git2::Delta::Deleted => FileChangeStatus::Deleted,
-                    git2::Delta::Modified => FileChangeStatus::Modified,
-                    git2::Delta::Renamed => FileChangeStatus::Renamed,
-                    git2::Delta::Copied => FileChangeStatus::Copied,
-                    git2::Delta::Ignored => FileChangeStatus::Ignored,
-                    git2::Delta::Untracked => FileChangeStatus::Untracked,
-                    git2::Delta::Typechange => FileChangeStatus::Typechange,
-                    git2::Delta::Unreadable => FileChangeStatus::Unreadable,
-                    git2::Delta::Conflicted => FileChangeStatus::Conflicted,
-                };
+        for (index, delta) in diff.deltas().enumerate() {
+            let status = match delta.status() {
+                git2::Delta::Unmodified => FileChangeStatus::Unmodified,
+                git2::Delta::Added => FileChangeStatus::Added,
+                git2::Delta::Deleted => FileChangeStatus::Deleted,
+                git2::Delta::Modified => FileChangeStatus::Modified,
+                git2::Delta::Renamed => FileChangeStatus::Renamed,
+                git2::Delta::Copied => FileChangeStatus::Copied,
+                git2::Delta::Ignored => FileChangeStatus::Ignored,
+                git2::Delta::Untracked => FileChangeStatus::Untracked,
+                git2::Delta::Typechange => FileChangeStatus::Typechange,
+                git2::Delta::Unreadable => FileChangeStatus::Unreadable,
+                git2::Delta::Conflicted => FileChangeStatus::Conflicted,
+            };
+
+            let path = if let Some(new_file) = delta.new_file().path() {
+                new_file
+            } else if let Some(old_file) = delta.old_file().path() {
+                old_file
+            } else {
+                continue; // Skip this delta
+            };
+
+            let Ok(file_path) = FilePathConverter::from_path_buf(path) else {
+                continue;
+            };
+            let renamed = matches!(status, FileChangeStatus::Renamed | FileChangeStatus::Copied);
+            let content_changed = (renamed || status == FileChangeStatus::Modified)
+                && delta.old_file().id() != delta.new_file().id();
+            let mut file_change = FileChange::new(file_path, status);
+            if self.is_generated(path)? {
+                file_change = file_change.with_generated();
+            }
+            if let Some(submodule) =
+                self.submodule_change(&delta, path, options.recurse_submodules)?
+            {
+                file_change = file_change.with_submodule(submodule);
+            }
+
+            if !options.line_stats && options.patch_context.is_none() && !whitespace.is_enabled() {
+                file_changes.push(file_change);
+                continue;
+            }
+
+            // libgit2 can only ignore all whitespace at the end of lines, so
+            // carriage returns are dropped from both sides before diffing.
+            let without_cr;
+            let patch = if whitespace.ignore_cr_at_eol
+                && content_changed
+                && !delta.flags().is_binary()
+                && delta.new_file().mode() != git2::FileMode::Commit
+            {
+                without_cr = (
+                    self.blob_without_cr(delta.old_file().id())?,
+                    self.blob_without_cr(delta.new_file().id())?,
+                );
+                git2::Patch::from_buffers(
+                    &without_cr.0,
+                    delta.old_file().path(),
+                    &without_cr.1,
+                    delta.new_file().path(),
+                    Some(&mut diff_options),
+                )
+                .map(Some)
+            } else {
+                git2::Patch::from_diff(&diff, index)
+            }
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to process diff: {}", e),
+            })?;
+
+            // The content changed, yet no hunks remain once whitespace is
+            // ignored.
+            if whitespace.is_enabled()
+                && content_changed
+                && !delta.flags().is_binary()
+                && patch.as_ref().is_some_and(|patch| patch.num_hunks() == 0)
+            {
+                file_change = file_change.with_whitespace_only();
+            }
 
-                let path = if let Some(new_file) = delta.new_file().path() {
-                    new_file
-                } else if let Some(old_file) = delta.old_file().path() {
-                    old_file
-                } else {
-                    return true; // Skip this delta
+            if options.line_stats {
+                // Binary files have no patch and count as no changed lines.
+                let lines = match &patch {
+                    Some(patch) => {
+                        let (_, added, removed) = patch.line_stats().map_err(|e| {
+                            GitRepositoryError::GitOperationFailed {
+                                message: format!("Failed to count changed lines: {}", e),
+                            }
+                        })?;
+                        LineStats::new(added, removed)
+                    }
+                    None => LineStats::default(),
                 };
+                file_change = file_change.with_lines(lines);
+            }
 
-                if let Ok(file_path) = FilePathConverter::from_path_buf(path) {
-                    file_changes.push(FileChange::new(file_path, status));
+            if options.patch_context.is_some() {
+                let mode =
+                    |file: git2::DiffFile| Some(u32::from(file.mode())).filter(|&mode| mode != 0);
+                let mut file_patch = match &patch {
+                    Some(patch) if !delta.flags().is_binary() => Self::file_patch(patch)?,
+                    _ => FilePatch::binary(),
+                }
+                .with_modes(mode(delta.old_file()), mode(delta.new_file()));
+                if renamed {
+                    if let Some(old_path) = delta
+                        .old_file()
+                        .path()
+                        .and_then(|path| FilePathConverter::from_path_buf(path).ok())
+                    {
+                        file_patch = file_patch.with_old_path(old_path);
+                    }
                 }
+                file_change = file_change.with_patch(file_patch);
+            }
 
-                true
-            },
-            None,
-            None,
-            None,
-        )
-        .map_err(|e| GitRepositoryError::GitOperationFailed {
-            message: format!("Failed to process diff: {}", e),
-        })?;
+            file_changes.push(file_change);
+        }
 
         Ok(file_changes)
     }
-}
 
-impl GitRepositoryImpl {
-    fn get_commits_from_oid(&self, start_oid: Oid) -> Result<Vec<Commit>, GitRepositoryError> {
+    /// The contents of blob `id` with the carriage return of every CRLF
+    /// line ending removed, for `WhitespaceOptions::ignore_cr_at_eol`.
+    fn blob_without_cr(&self, id: Oid) -> Result<Vec<u8>, GitRepositoryError> {
+        let blob = self
+            .repo
+            .find_blob(id)
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to read blob {}: {}", id, e),
+            })?;
+        let content = blob.content();
+
+        Ok(content
+            .iter()
+            .enumerate()
+            .filter(|&(i, &byte)| {
+                byte != b'\r' || !matches!(content.get(i + 1), None | Some(b'\n'))
+            })
+            .map(|(_, &byte)| byte)
+            .collect())
+    }
+
+    /// The pointers of a delta with a submodule on either side, and with
+    /// `recurse` the commits between them.
+    fn submodule_change(
+        &self,
+        delta: &git2::DiffDelta,
+        path: &std::path::Path,
+        recurse: bool,
+    ) -> Result<Option<SubmoduleChange>, GitRepositoryError> {
+        let commit_id = |file: git2::DiffFile| {
+            (file.mode() == git2::FileMode::Commit && !file.id().is_zero()).then(|| file.id())
+        };
+        let (old_oid, new_oid) = (commit_id(delta.old_file()), commit_id(delta.new_file()));
+        if old_oid.is_none() && new_oid.is_none() {
+            return Ok(None);
+        }
+
+        let to_hash = |oid: Oid| {
+            CommitHash::new(oid.to_string()).map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Invalid submodule commit hash: {}", e),
+            })
+        };
+        let change = SubmoduleChange::new(
+            old_oid.map(to_hash).transpose()?,
+            new_oid.map(to_hash).transpose()?,
+        );
+
+        let (true, Some(old_oid), Some(new_oid)) = (recurse, old_oid, new_oid) else {
+            return Ok(Some(change));
+        };
+        Ok(Some(match self.submodule_commits(path, old_oid, new_oid) {
+            Some(commits) => change.with_commits(commits),
+            None => change,
+        }))
+    }
+
+    /// Commits after `old_oid` up to `new_oid` in the submodule at `path`,
+    /// or `None` when it is not checked out or lacks either commit.
+    fn submodule_commits(
+        &self,
+        path: &std::path::Path,
+        old_oid: Oid,
+        new_oid: Oid,
+    ) -> Option<Vec<Commit>> {
+        let repo = self
+            .repo
+            .find_submodule(path.to_str()?)
+            .and_then(|submodule| submodule.open())
+            .ok()?;
+        let submodule = Self { repo };
+        submodule.repo.find_commit(old_oid).ok()?;
+        submodule.get_commits_from_oid(new_oid, Some(old_oid)).ok()
+    }
+
+    /// Whether `.gitattributes`, read from the HEAD commit, sets
+    /// `linguist-generated` or unsets `diff` for the path.
+    ///
+    /// Uncommitted and staged edits to `.gitattributes` are ignored, so the
+    /// result only depends on the compared commits.
+    fn is_generated(&self, path: &std::path::Path) -> Result<bool, GitRepositoryError> {
+        // libgit2's `GIT_ATTR_CHECK_INCLUDE_HEAD`, which git2 doesn't expose.
+        const INCLUDE_HEAD: u32 = 1 << 3;
+        // Setting both low bits selects neither the working tree nor the index.
+        let head_only = git2::AttrCheckFlags::from_bits_retain(
+            (git2::AttrCheckFlags::INDEX_THEN_FILE | git2::AttrCheckFlags::INDEX_ONLY).bits()
+                | INCLUDE_HEAD,
+        ) | git2::AttrCheckFlags::NO_SYSTEM;
+        let attribute = |name: &str| {
+            self.repo
+                .get_attr_bytes(path, name, head_only)
+                .map(git2::AttrValue::from_bytes)
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to read attributes of {}: {}", path.display(), e),
+                })
+        };
+
+        let generated = matches!(
+            attribute("linguist-generated")?,
+            git2::AttrValue::True | git2::AttrValue::String("true")
+        );
+        Ok(generated || matches!(attribute("diff")?, git2::AttrValue::False))
+    }
+
+    /// Converts a libgit2 patch into hunks. The "no newline at end of
+    /// file" markers are dropped.
+    fn file_patch(patch: &git2::Patch) -> Result<FilePatch, GitRepositoryError> {
+        let to_error = |e: git2::Error| GitRepositoryError::GitOperationFailed {
+            message: format!("Failed to read patch: {}", e),
+        };
+
+        let mut hunks = Vec::with_capacity(patch.num_hunks());
+        for hunk_index in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(hunk_index).map_err(to_error)?;
+            let header = String::from_utf8_lossy(hunk.header());
+            // The header is `@@ -a,b +c,d @@ context\n`.
+            let header = header
+                .splitn(3, "@@")
+                .nth(2)
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+
+            let mut lines = Vec::with_capacity(line_count);
+            for line_index in 0..line_count {
+                let line = patch
+                    .line_in_hunk(hunk_index, line_index)
+                    .map_err(to_error)?;
+                let kind = match line.origin() {
+                    ' ' => DiffLineKind::Context,
+                    '+' => DiffLineKind::Added,
+                    '-' => DiffLineKind::Removed,
+                    _ => continue,
+                };
+                let content = String::from_utf8_lossy(line.content());
+                lines.push(DiffLine {
+                    kind,
+                    content: content.strip_suffix('\n').unwrap_or(&content).to_string(),
+                });
+            }
+
+            hunks.push(Hunk {
+                old_start: hunk.old_start(),
+                old_lines: hunk.old_lines(),
+                new_start: hunk.new_start(),
+                new_lines: hunk.new_lines(),
+                header,
+                lines,
+            });
+        }
+
+        Ok(FilePatch::new(hunks))
+    }
+
+    /// The signature block of the commit `oid` and the signed payload, or
+    /// `None` if the commit is not signed.
+    fn extract_signature(&self, oid: Oid) -> Result<Option<(String, Vec<u8>)>, GitRepositoryError> {
+        match self.repo.extract_signature(&oid, None) {
+            Ok((signature, payload)) => Ok(Some((
+                String::from_utf8_lossy(&signature).into_owned(),
+                payload.to_vec(),
+            ))),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to read signature of commit {}: {}", oid, e),
+            }),
+        }
+    }
+
+    /// Resolves `target` as `find_branch_commit` does, or HEAD when `None`.
+    fn find_target_commit(
+        &self,
+        target: Option<&BranchName>,
+    ) -> Result<git2::Commit<'_>, GitRepositoryError> {
+        match target {
+            Some(target) => self.find_branch_commit(target),
+            None => self
+                .repo
+                .head()
+                .and_then(|head| head.peel_to_commit())
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to get HEAD commit: {}", e),
+                }),
+        }
+    }
+
+    /// Resolves `branch` as a local branch, a remote branch or a tag, in
+    /// that order.
+    fn find_branch_commit(
+        &self,
+        branch: &BranchName,
+    ) -> Result<git2::Commit<'_>, GitRepositoryError> {
+        let reference = self
+            .repo
+            .find_branch(branch.as_str(), BranchType::Local)
+            .or_else(|_| self.repo.find_branch(branch.as_str(), BranchType::Remote))
+            .map(git2::Branch::into_reference)
+            .or_else(|_| {
+                self.repo
+                    .find_reference(&format!("refs/tags/{}", branch.as_str()))
+            })
+            .map_err(|_| GitRepositoryError::BranchNotFound {
+                branch: branch.to_string(),
+                suggestions: BranchSuggestionDomainService::suggest(
+                    branch.as_str(),
+                    &self.ref_names(),
+                ),
+            })?;
+
+        reference
+            .peel_to_commit()
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to get branch commit: {}", e),
+            })
+    }
+
+    /// Commits reachable from `start_oid`, leaving out those reachable from
+    /// `hide_oid`, in topological order with every commit before its
+    /// parents, newest first otherwise. The revwalk drops hidden commits
+    /// before they are read, so mailmap, message decoding and signature
+    /// extraction only run for the commits returned.
+    fn get_commits_from_oid(
+        &self,
+        start_oid: Oid,
+        hide_oid: Option<Oid>,
+    ) -> Result<Vec<Commit>, GitRepositoryError> {
         let mut revwalk =
             self.repo
                 .revwalk()
                 .map_err(|e| GitRepositoryError::GitOperationFailed {
                     message: format!("Failed to create revwalk: {}", e),
                 })?;
+        revwalk
+            .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to sort revwalk: {}", e),
+            })?;
         revwalk
             .push(start_oid)
             .map_err(|e| GitRepositoryError::GitOperationFailed {
                 message: format!("Failed to push OID to revwalk: {}", e),
             })?;
+        if let Some(hide_oid) = hide_oid {
+            revwalk
+                .hide(hide_oid)
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to hide OID in revwalk: {}", e),
+                })?;
+        }
+
+        let mailmap = self
+            .repo
+            .mailmap()
+            .map_err(|e| GitRepositoryError::GitOperationFailed {
+                message: format!("Failed to read mailmap: {}", e),
+            })?;
 
         let mut commits = Vec::new();
 
@@ -180,7 +771,11 @@ impl GitRepositoryImpl {
                     .map_err(|e| GitRepositoryError::GitOperationFailed {
                         message: format!("Failed to find commit: {}", e),
                     })?;
-            let author = commit.author();
+            let author = commit.author_with_mailmap(&mailmap).map_err(|e| {
+                GitRepositoryError::GitOperationFailed {
+                    message: format!("Failed to apply mailmap to commit {}: {}", oid, e),
+                }
+            })?;
 
             let hash = CommitHash::new(oid.to_string()).map_err(|e| {
                 GitRepositoryError::GitOperationFailed {
@@ -200,12 +795,25 @@ impl GitRepositoryImpl {
                     .ok_or_else(|| GitRepositoryError::GitOperationFailed {
                         message: format!("Missing author email for commit {}", oid),
                     })?;
-            let commit_message =
-                commit
-                    .summary()
-                    .ok_or_else(|| GitRepositoryError::GitOperationFailed {
-                        message: format!("Missing commit message for commit {}", oid),
-                    })?;
+            // Messages are stored in the encoding named by the commit's
+            // `encoding` header, or UTF-8 when there is none.
+            let encoding = commit
+                .message_encoding()
+                .and_then(|label| TextEncoding::new(label).ok())
+                .unwrap_or_default();
+            let commit_message = commit
+                .summary_bytes()
+                .map(|bytes| encoding.decode(bytes))
+                .ok_or_else(|| GitRepositoryError::GitOperationFailed {
+                    message: format!("Missing commit message for commit {}", oid),
+                })?;
+            let parents = commit
+                .parent_ids()
+                .map(|parent| CommitHash::new(parent.to_string()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| GitRepositoryError::GitOperationFailed {
+                    message: format!("Invalid parent hash for commit {}: {}", oid, e),
+                })?;
 
             let domain_commit = Commit::new(
                 hash,
@@ -213,7 +821,22 @@ impl GitRepositoryImpl {
                 author_email.to_string(),
                 commit.time().seconds(),
                 commit_message.to_string(),
-            );
+            )
+            .with_body(
+                commit
+                    .body_bytes()
+                    .map(|bytes| encoding.decode(bytes).into_owned())
+                    .unwrap_or_default(),
+            )
+            .with_parents(parents)
+            .with_offset_minutes(commit.time().offset_minutes());
+            let domain_commit = match self
+                .extract_signature(oid)?
+                .and_then(|(signature, _)| SignatureFormat::detect(&signature))
+            {
+                Some(format) => domain_commit.with_signature(format),
+                None => domain_commit,
+            };
 
             commits.push(domain_commit);
         }
@@ -221,3 +844,81 @@ impl GitRepositoryImpl {
         Ok(commits)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::Signature;
+    use tempfile::TempDir;
+
+    /// A repository whose `master` has one commit, with a `base` branch
+    /// pointing at it.
+    fn repository() -> (TempDir, Repository) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let root = commit(&repo, Some("HEAD"), "Initial commit", &[]);
+        repo.branch("base", &repo.find_commit(root).unwrap(), false)
+            .unwrap();
+        (dir, repo)
+    }
+
+    fn commit(repo: &Repository, update_ref: Option<&str>, message: &str, parents: &[Oid]) -> Oid {
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        let tree = repo
+            .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+            .unwrap();
+        let parents: Vec<git2::Commit> = parents
+            .iter()
+            .map(|oid| repo.find_commit(*oid).unwrap())
+            .collect();
+        let parents: Vec<&git2::Commit> = parents.iter().collect();
+        repo.commit(update_ref, &signature, &signature, message, &tree, &parents)
+            .unwrap()
+    }
+
+    fn messages_not_in(git_repo: &GitRepositoryImpl, branch: &str) -> Vec<String> {
+        git_repo
+            .get_commits_not_in_branch(&BranchName::new(branch.to_string()).unwrap(), None)
+            .unwrap()
+            .iter()
+            .map(|commit| commit.message().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_commits_not_in_branch() {
+        let (dir, repo) = repository();
+        let head = repo.head().unwrap().target().unwrap();
+        commit(&repo, Some("refs/heads/base"), "Base commit", &[head]);
+        let first = commit(&repo, Some("HEAD"), "First commit", &[head]);
+        commit(&repo, Some("HEAD"), "Second commit", &[first]);
+
+        let git_repo = GitRepositoryImpl::open(dir.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            messages_not_in(&git_repo, "base"),
+            vec!["Second commit", "First commit"]
+        );
+    }
+
+    #[test]
+    fn test_all_commits_in_branch() {
+        let (dir, repo) = repository();
+        let head = repo.head().unwrap().target().unwrap();
+        let shared = commit(&repo, Some("HEAD"), "Shared commit", &[head]);
+        repo.reference("refs/heads/base", shared, true, "fast-forward")
+            .unwrap();
+        commit(&repo, Some("refs/heads/base"), "Base commit", &[shared]);
+
+        let git_repo = GitRepositoryImpl::open(dir.path().to_str().unwrap()).unwrap();
+        assert!(messages_not_in(&git_repo, "base").is_empty());
+    }
+
+    #[test]
+    fn test_unrelated_branch() {
+        let (dir, repo) = repository();
+        commit(&repo, Some("refs/heads/orphan"), "Orphan commit", &[]);
+
+        let git_repo = GitRepositoryImpl::open(dir.path().to_str().unwrap()).unwrap();
+        assert_eq!(messages_not_in(&git_repo, "orphan"), vec!["Initial commit"]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod signature_verifier;
+
+pub use signature_verifier::SignatureVerifier;
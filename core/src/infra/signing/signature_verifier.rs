@@ -0,0 +1,230 @@
+use crate::domain::value_objects::{SignatureFormat, SignatureStatus, SignatureVerification};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// The namespace git signs commits in with SSH keys.
+const SSH_NAMESPACE: &str = "git";
+
+/// Verifies commit signatures with the same external programs git uses:
+/// `gpg` for GPG signatures and `ssh-keygen -Y` for SSH signatures.
+pub struct SignatureVerifier<'a> {
+    verification: &'a SignatureVerification,
+}
+
+impl<'a> SignatureVerifier<'a> {
+    pub fn new(verification: &'a SignatureVerification) -> Self {
+        Self { verification }
+    }
+
+    /// Checks `signature` over `payload`, the commit object without its
+    /// signature header. X.509 signatures cannot be verified and are
+    /// reported as an unknown key, so they never count as good.
+    pub fn verify(
+        &self,
+        format: SignatureFormat,
+        signature: &str,
+        payload: &[u8],
+    ) -> io::Result<SignatureStatus> {
+        let mut signature_file = tempfile::Builder::new()
+            .prefix("arlon-signature-")
+            .suffix(".sig")
+            .tempfile()?;
+        signature_file.write_all(signature.as_bytes())?;
+
+        match format {
+            SignatureFormat::Gpg => self.verify_gpg(signature_file.path(), payload),
+            SignatureFormat::Ssh => self.verify_ssh(signature_file.path(), payload),
+            SignatureFormat::X509 => Ok(SignatureStatus::UnknownKey),
+        }
+    }
+
+    fn verify_gpg(&self, signature: &Path, payload: &[u8]) -> io::Result<SignatureStatus> {
+        let mut command = Command::new("gpg");
+        command.args(["--batch", "--status-fd=1"]);
+        if let Some(keyring) = &self.verification.gpg_keyring {
+            command
+                .arg("--no-default-keyring")
+                .arg("--keyring")
+                .arg(keyring);
+        }
+        command.arg("--verify").arg(signature).arg("-");
+
+        // Every key in a keyring named for verification is trusted; keys in
+        // the default keyring need at least marginal owner trust.
+        let keyring_trusted = self.verification.gpg_keyring.is_some();
+        let (succeeded, stdout) = run(&mut command, payload)?;
+        Ok(match gpg_status(&stdout, keyring_trusted) {
+            SignatureStatus::Good if !succeeded => SignatureStatus::Bad,
+            status => status,
+        })
+    }
+
+    fn verify_ssh(&self, signature: &Path, payload: &[u8]) -> io::Result<SignatureStatus> {
+        let mut check = Command::new("ssh-keygen");
+        check
+            .args(["-Y", "check-novalidate", "-n", SSH_NAMESPACE, "-s"])
+            .arg(signature);
+        if !run(&mut check, payload)?.0 {
+            return Ok(SignatureStatus::Bad);
+        }
+
+        let Some(allowed_signers) = &self.verification.allowed_signers else {
+            return Ok(SignatureStatus::UnknownKey);
+        };
+
+        let mut find_principals = Command::new("ssh-keygen");
+        find_principals
+            .args(["-Y", "find-principals", "-f"])
+            .arg(allowed_signers)
+            .arg("-s")
+            .arg(signature);
+        let (found, principals) = run(&mut find_principals, &[])?;
+        let Some(principal) = principals.lines().next().filter(|_| found) else {
+            return Ok(SignatureStatus::UnknownKey);
+        };
+
+        let mut verify = Command::new("ssh-keygen");
+        verify
+            .args(["-Y", "verify", "-f"])
+            .arg(allowed_signers)
+            .args(["-I", principal, "-n", SSH_NAMESPACE, "-s"])
+            .arg(signature);
+        Ok(if run(&mut verify, payload)?.0 {
+            SignatureStatus::Good
+        } else {
+            SignatureStatus::Bad
+        })
+    }
+}
+
+/// Maps `gpg --status-fd` output onto a status, as git's `parse_gpg_output`.
+/// A good signature from a key without marginal, full or ultimate trust is
+/// an unknown key unless `keyring_trusted`, as `gpg.minTrustLevel=marginal`.
+fn gpg_status(status: &str, keyring_trusted: bool) -> SignatureStatus {
+    let keywords: Vec<&str> = status
+        .lines()
+        .filter_map(|line| line.strip_prefix("[GNUPG:] "))
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+
+    if keywords
+        .iter()
+        .any(|keyword| matches!(*keyword, "BADSIG" | "EXPSIG" | "EXPKEYSIG" | "REVKEYSIG"))
+    {
+        SignatureStatus::Bad
+    } else if keywords.contains(&"GOODSIG")
+        && (keyring_trusted
+            || keywords.iter().any(|keyword| {
+                matches!(
+                    *keyword,
+                    "TRUST_MARGINAL" | "TRUST_FULLY" | "TRUST_ULTIMATE"
+                )
+            }))
+    {
+        SignatureStatus::Good
+    } else {
+        SignatureStatus::UnknownKey
+    }
+}
+
+/// Runs `command` with `input` on stdin, returning whether it succeeded
+/// and its stdout.
+///
+/// The input is written from another thread while stdout is read, so a
+/// payload larger than the pipe buffer cannot deadlock. A command that
+/// exits before reading all of it has not verified it and counts as failed.
+fn run(command: &mut Command, input: &[u8]) -> io::Result<(bool, String)> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let stdin = child.stdin.take();
+
+    let (written, output) = thread::scope(|scope| {
+        let writer = scope.spawn(move || match stdin {
+            Some(mut stdin) => match stdin.write_all(input) {
+                Ok(()) => Ok(true),
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(false),
+                Err(e) => Err(e),
+            },
+            None => Ok(true),
+        });
+        let output = child.wait_with_output();
+        let written = writer
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("stdin writer panicked")));
+        (written, output)
+    });
+    let (written, output) = (written?, output?);
+
+    Ok((
+        written && output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpg_status() {
+        assert_eq!(
+            gpg_status(
+                "[GNUPG:] NEWSIG\n[GNUPG:] GOODSIG 1234 Alice\n[GNUPG:] VALIDSIG 1234\n[GNUPG:] TRUST_FULLY 0 pgp\n",
+                false
+            ),
+            SignatureStatus::Good
+        );
+        let untrusted = "[GNUPG:] GOODSIG 1234 Alice\n[GNUPG:] TRUST_UNDEFINED 0 pgp\n";
+        assert_eq!(gpg_status(untrusted, false), SignatureStatus::UnknownKey);
+        assert_eq!(gpg_status(untrusted, true), SignatureStatus::Good);
+        assert_eq!(
+            gpg_status("[GNUPG:] BADSIG 1234 Alice\n", false),
+            SignatureStatus::Bad
+        );
+        assert_eq!(
+            gpg_status(
+                "[GNUPG:] GOODSIG 1234 Alice\n[GNUPG:] EXPKEYSIG 1234 Alice\n",
+                false
+            ),
+            SignatureStatus::Bad
+        );
+        assert_eq!(
+            gpg_status(
+                "[GNUPG:] ERRSIG 1234 1 10 00 0 9\n[GNUPG:] NO_PUBKEY 1234\n",
+                false
+            ),
+            SignatureStatus::UnknownKey
+        );
+    }
+
+    #[test]
+    fn test_x509_is_unknown_key() {
+        let verification = SignatureVerification::default();
+        let status = SignatureVerifier::new(&verification)
+            .verify(
+                SignatureFormat::X509,
+                "-----BEGIN SIGNED MESSAGE-----",
+                b"tree",
+            )
+            .unwrap();
+        assert_eq!(status, SignatureStatus::UnknownKey);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_large_input() {
+        let input = vec![b'a'; 1 << 20];
+        let (succeeded, stdout) = run(&mut Command::new("cat"), &input).unwrap();
+        assert!(succeeded);
+        assert_eq!(stdout.len(), input.len());
+
+        // Exiting without reading stdin is a failure, not an error
+        let (succeeded, _) = run(&mut Command::new("true"), &input).unwrap();
+        assert!(!succeeded);
+    }
+}
@@ -3,10 +3,23 @@ pub mod domain;
 pub mod infra;
 
 // Re-export commonly used types
-pub use application::dto::{CommitDto, FileDto};
-pub use application::use_cases::{CompareCommitsUseCase, CompareFilesUseCase};
+pub use application::dto::{
+    AuthorDto, BaseBranchDto, ChangelogDto, ChangelogOutputDto, CommitDto, DirStatDto, ErrorDto,
+    ErrorOutputDto, FileDto, HiddenFilesDto, IssueDto, LintDto, OutputDto, OwnerGroupDto,
+    PackageDto,
+};
+pub use application::use_cases::{
+    CompareAuthorsUseCase, CompareCommitsUseCase, CompareFilesUseCase, CompareIssuesUseCase,
+    DetectAffectedPackagesUseCase, DetectBaseBranchUseCase, GenerateChangelogUseCase,
+    LintCommitsUseCase,
+};
 pub use domain::entities::{Commit, FileChange};
 pub use domain::repositories::{GitRepository, GitRepositoryError};
-pub use domain::value_objects::{BranchName, CommitHash, FilePath};
+pub use domain::value_objects::{
+    BranchName, BranchNameError, CodeOwners, CommitHash, ComparisonEndpoints, DateFormat,
+    ExcludePatterns, FileComparisonOptions, FilePath, IssuePatterns, LintRules, PackageGlobs,
+    RenameDetection, SignatureVerification, TextEncoding, WhitespaceOptions,
+};
+pub use infra::config::{ConfigError, ConfigFile, ConfigLoader};
 pub use infra::output::{JsonFormatter, OutputFormatter, SimpleFormatter};
 pub use infra::repositories::GitRepositoryImpl;
@@ -1,9 +1,13 @@
 use arlon_core::domain::repositories::GitRepository;
-use arlon_core::domain::services::CommitComparisonDomainService;
-use arlon_core::domain::value_objects::BranchName;
+use arlon_core::domain::value_objects::{
+    BranchName, CommitHash, FileComparisonOptions, FilePath, LineStats, RenameDetection,
+    SignatureFormat, SignatureStatus, SignatureVerification, WhitespaceOptions,
+};
 use arlon_core::infra::repositories::GitRepositoryImpl;
 use git2::{Oid, Repository, Signature};
 use std::fs;
+use std::path::Path;
+use std::process::Command;
 use tempfile::TempDir;
 
 pub struct TestGitRepo {
@@ -101,6 +105,131 @@ impl TestGitRepo {
     }
 }
 
+impl TestGitRepo {
+    pub fn rename_file_on_current_branch(
+        &self,
+        message: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Oid, git2::Error> {
+        let signature = Signature::now("Test User", "test@example.com")?;
+
+        fs::rename(
+            self.temp_dir.path().join(from),
+            self.temp_dir.path().join(to),
+        )
+        .map_err(|e| git2::Error::from_str(&e.to_string()))?;
+
+        let mut index = self.repo.index()?;
+        index.remove_path(std::path::Path::new(from))?;
+        index.add_path(std::path::Path::new(to))?;
+        index.write()?;
+
+        let tree_id = index.write_tree()?;
+        let tree = self.repo.find_tree(tree_id)?;
+
+        let head = self.repo.head()?;
+        let parent_commit = head.peel_to_commit()?;
+
+        self.repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &[&parent_commit],
+        )
+    }
+}
+
+impl TestGitRepo {
+    /// Commits `message` on top of HEAD, signed with the SSH private key at
+    /// `key` the way `git commit -S` does with `gpg.format=ssh`.
+    pub fn create_ssh_signed_commit(
+        &self,
+        message: &str,
+        key: &Path,
+    ) -> Result<Oid, Box<dyn std::error::Error>> {
+        let signature = Signature::now("Test User", "test@example.com")?;
+        let head = self.repo.head()?.peel_to_commit()?;
+        let tree = head.tree()?;
+        let payload =
+            self.repo
+                .commit_create_buffer(&signature, &signature, message, &tree, &[&head])?;
+
+        let payload_path = key.with_extension("payload");
+        fs::write(&payload_path, &*payload)?;
+        let status = Command::new("ssh-keygen")
+            .args(["-q", "-Y", "sign", "-n", "git", "-f"])
+            .arg(key)
+            .arg(&payload_path)
+            .status()?;
+        if !status.success() {
+            return Err("ssh-keygen -Y sign failed".into());
+        }
+        let ssh_signature = fs::read_to_string(payload_path.with_extension("payload.sig"))?;
+
+        let oid = self.repo.commit_signed(
+            std::str::from_utf8(&payload)?,
+            &ssh_signature,
+            Some("gpgsig"),
+        )?;
+        self.repo.head()?.set_target(oid, "commit (signed)")?;
+        Ok(oid)
+    }
+}
+
+/// Generates an ed25519 key pair in `dir`, returning the private key path,
+/// or `None` if `ssh-keygen` is not installed.
+fn generate_ssh_key(dir: &Path) -> Option<std::path::PathBuf> {
+    let key = dir.join("id_ed25519");
+    let status = Command::new("ssh-keygen")
+        .args([
+            "-q",
+            "-t",
+            "ed25519",
+            "-N",
+            "",
+            "-C",
+            "test@example.com",
+            "-f",
+        ])
+        .arg(&key)
+        .status()
+        .ok()?;
+    status.success().then_some(key)
+}
+
+/// Runs the git command line in `dir`, for setups libgit2 cannot do, such
+/// as adding submodules.
+fn run_git(dir: &Path, args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=Test User",
+            "-c",
+            "user.email=test@example.com",
+            "-c",
+            "protocol.file.allow=always",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .status()?;
+    if !status.success() {
+        return Err(format!("git {} failed", args.join(" ")).into());
+    }
+    Ok(())
+}
+
+/// Whether the git command line is installed, for the tests that need
+/// `run_git`.
+fn git_available() -> bool {
+    Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
@@ -124,13 +253,46 @@ mod integration_tests {
         let git_repo =
             GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
 
-        let branch_name = BranchName::new("main".to_string()).unwrap();
-        let head_commits = git_repo.get_commits_from_head().unwrap();
-        let branch_commits = git_repo.get_commits_from_branch(&branch_name).unwrap();
-
-        let commits =
-            CommitComparisonDomainService::commits_not_in_branch(head_commits, branch_commits);
+        let main = BranchName::new("main".to_string()).unwrap();
+        let master = BranchName::new("master".to_string()).unwrap();
+        let commits = git_repo.get_commits_not_in_branch(&main, None).unwrap();
         assert_eq!(commits.len(), 0);
+
+        let commits = git_repo
+            .get_commits_not_in_branch(&master, Some(main))
+            .unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message(), "Add feature to main");
+    }
+
+    #[test]
+    fn test_git2_repository_resolve_endpoints() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let initial = test_repo.repo.head().unwrap().target().unwrap();
+        test_repo.create_branch("main").unwrap();
+        test_repo.checkout_branch("main").unwrap();
+        let main = test_repo
+            .create_commit_on_current_branch("Add feature to main", &[("main.txt", "main\n")])
+            .unwrap();
+        test_repo.checkout_branch("master").unwrap();
+        let head = test_repo
+            .create_commit_on_current_branch("Add feature to master", &[("head.txt", "head\n")])
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let hash = |oid: Oid| CommitHash::new(oid.to_string()).unwrap();
+        let branch = BranchName::new("main".to_string()).unwrap();
+
+        let endpoints = git_repo.resolve_endpoints(&branch, None).unwrap();
+        assert_eq!(endpoints.base, hash(main));
+        assert_eq!(endpoints.target, hash(head));
+        assert_eq!(endpoints.merge_base, Some(hash(initial)));
+
+        let master = BranchName::new("master".to_string()).unwrap();
+        let endpoints = git_repo.resolve_endpoints(&master, Some(branch)).unwrap();
+        assert_eq!(endpoints.base, hash(head));
+        assert_eq!(endpoints.target, hash(main));
     }
 
     #[test]
@@ -152,7 +314,8 @@ mod integration_tests {
             GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
 
         let branch_name = BranchName::new("master".to_string()).unwrap();
-        let result = git_repo.get_file_changes_between_branches(&branch_name);
+        let result = git_repo
+            .get_file_changes_between_branches(&branch_name, &FileComparisonOptions::default());
 
         assert!(result.is_ok());
         let file_changes = result.unwrap();
@@ -183,16 +346,840 @@ mod integration_tests {
             GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
 
         let branch_name = BranchName::new("nonexistent".to_string()).unwrap();
-        let head_result = git_repo.get_commits_from_head();
-        let branch_result = git_repo.get_commits_from_branch(&branch_name);
+        let branch_result = git_repo.get_commits_not_in_branch(&branch_name, None);
 
-        assert!(head_result.is_ok());
         assert!(branch_result.is_err());
         match branch_result.unwrap_err() {
-            arlon_core::domain::repositories::GitRepositoryError::BranchNotFound { branch } => {
+            arlon_core::domain::repositories::GitRepositoryError::BranchNotFound {
+                branch, ..
+            } => {
                 assert_eq!(branch, "nonexistent");
             }
             _ => panic!("Expected BranchNotFound error"),
         }
     }
+
+    #[test]
+    fn test_git2_repository_open_not_a_repository() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let result = GitRepositoryImpl::open(temp_dir.path().to_str().unwrap());
+
+        assert!(matches!(
+            result,
+            Err(arlon_core::domain::repositories::GitRepositoryError::RepositoryNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_git2_repository_branch_not_found_suggestions() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main").unwrap();
+        test_repo.create_branch("develop").unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+
+        let branch_name = BranchName::new("mian".to_string()).unwrap();
+        let result = git_repo
+            .get_file_changes_between_branches(&branch_name, &FileComparisonOptions::default());
+
+        match result.unwrap_err() {
+            arlon_core::domain::repositories::GitRepositoryError::BranchNotFound {
+                branch,
+                suggestions,
+            } => {
+                assert_eq!(branch, "mian");
+                assert_eq!(suggestions, vec!["main"]);
+            }
+            _ => panic!("Expected BranchNotFound error"),
+        }
+    }
+
+    #[test]
+    fn test_git2_repository_suggestions_resolve() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let head = test_repo.repo.head().unwrap().peel_to_commit().unwrap();
+        test_repo
+            .repo
+            .reference("refs/remotes/origin/release", head.id(), false, "")
+            .unwrap();
+        test_repo
+            .repo
+            .tag_lightweight("v1.0.0", head.as_object(), false)
+            .unwrap();
+        test_repo
+            .repo
+            .tag_lightweight("v1.0.0-tree", head.tree().unwrap().as_object(), false)
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let suggestions = |name: &str| -> Vec<String> {
+            let branch = BranchName::new(name.to_string()).unwrap();
+            match git_repo.get_commits_not_in_branch(&branch, None) {
+                Err(arlon_core::domain::repositories::GitRepositoryError::BranchNotFound {
+                    suggestions,
+                    ..
+                }) => suggestions,
+                _ => panic!("Expected BranchNotFound error"),
+            }
+        };
+
+        // A tag that does not point at a commit cannot be compared against.
+        assert_eq!(suggestions("relase"), vec!["origin/release"]);
+        assert_eq!(suggestions("v1.0.1"), vec!["v1.0.0"]);
+        assert!(suggestions("v1.0.0-tre").is_empty());
+        for name in ["origin/release", "v1.0.0"] {
+            let branch = BranchName::new(name.to_string()).unwrap();
+            assert!(git_repo.get_commits_not_in_branch(&branch, None).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_git2_repository_rename_detection() {
+        let test_repo = TestGitRepo::new().unwrap();
+
+        test_repo.create_branch("feature").unwrap();
+        test_repo.checkout_branch("feature").unwrap();
+        test_repo
+            .rename_file_on_current_branch("Rename README", "README.md", "GUIDE.md")
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch_name = BranchName::new("master".to_string()).unwrap();
+
+        let without_renames = git_repo
+            .get_file_changes_between_branches(&branch_name, &FileComparisonOptions::default())
+            .unwrap();
+        assert_eq!(without_renames.len(), 2);
+
+        let options = FileComparisonOptions {
+            renames: RenameDetection::Enabled { threshold: 50 },
+            ..FileComparisonOptions::default()
+        };
+        let with_renames = git_repo
+            .get_file_changes_between_branches(&branch_name, &options)
+            .unwrap();
+        assert_eq!(with_renames.len(), 1);
+        assert_eq!(with_renames[0].status().as_str(), "renamed");
+        assert_eq!(with_renames[0].path().as_str(), "GUIDE.md");
+    }
+
+    #[test]
+    fn test_git2_repository_base_branch_candidates() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo
+            .repo
+            .remote("origin", "https://example.com/repo.git")
+            .unwrap();
+        let head = test_repo.repo.head().unwrap().target().unwrap();
+        test_repo
+            .repo
+            .reference("refs/remotes/origin/main", head, false, "fetch")
+            .unwrap();
+        test_repo
+            .repo
+            .reference("refs/remotes/origin/feature", head, false, "fetch")
+            .unwrap();
+        test_repo
+            .repo
+            .reference_symbolic(
+                "refs/remotes/origin/HEAD",
+                "refs/remotes/origin/main",
+                false,
+                "clone",
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let candidates = git_repo.get_base_branch_candidates().unwrap();
+
+        assert!(candidates.upstream.is_none());
+        assert_eq!(candidates.remote_default.unwrap().as_str(), "origin/main");
+        let local: Vec<&str> = candidates
+            .local_defaults
+            .iter()
+            .map(|branch| branch.as_str())
+            .collect();
+        assert_eq!(local, vec!["master"]);
+        let remote: Vec<&str> = candidates
+            .remote_defaults
+            .iter()
+            .map(|branch| branch.as_str())
+            .collect();
+        assert_eq!(remote, vec!["origin/main"]);
+
+        test_repo.create_branch("feature").unwrap();
+        test_repo.checkout_branch("feature").unwrap();
+        test_repo
+            .repo
+            .find_branch("feature", git2::BranchType::Local)
+            .unwrap()
+            .set_upstream(Some("origin/feature"))
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let candidates = git_repo.get_base_branch_candidates().unwrap();
+        assert_eq!(candidates.upstream.unwrap().as_str(), "origin/feature");
+
+        let remote_branch = BranchName::new("origin/main".to_string()).unwrap();
+        assert!(git_repo
+            .get_commits_not_in_branch(&remote_branch, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_git2_repository_applies_mailmap() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("base").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Add mailmap",
+                &[(
+                    ".mailmap",
+                    "Canonical User <canonical@example.com> <test@example.com>\n",
+                )],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let base = BranchName::new("base".to_string()).unwrap();
+        let commits = git_repo.get_commits_not_in_branch(&base, None).unwrap();
+
+        assert_eq!(commits.len(), 1);
+        for commit in &commits {
+            assert_eq!(commit.author(), "Canonical User");
+            assert_eq!(commit.email(), "canonical@example.com");
+        }
+    }
+
+    #[test]
+    fn test_git2_repository_commit_line_stats() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let oid = test_repo
+            .create_commit_on_current_branch(
+                "Rewrite README",
+                &[("README.md", "# Renamed\nLine two\nLine three\n")],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let hash = CommitHash::new(oid.to_string()).unwrap();
+        let stats = git_repo.get_commit_line_stats(&hash).unwrap();
+
+        assert_eq!(stats, LineStats::new(3, 1));
+    }
+
+    #[test]
+    fn test_git2_repository_commit_file_changes() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let oid = test_repo
+            .create_commit_on_current_branch(
+                "Add sources",
+                &[
+                    ("src/lib.rs", "pub fn lib() {}\n"),
+                    ("README.md", "# Docs\n"),
+                ],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let changes = |oid: Oid| -> Vec<(String, &'static str)> {
+            let hash = CommitHash::new(oid.to_string()).unwrap();
+            git_repo
+                .get_commit_file_changes(&hash, &FileComparisonOptions::default())
+                .unwrap()
+                .iter()
+                .map(|change| (change.path().to_string(), change.status().as_str()))
+                .collect()
+        };
+
+        assert_eq!(
+            changes(oid),
+            vec![
+                ("README.md".to_string(), "modified"),
+                ("src/lib.rs".to_string(), "added"),
+            ]
+        );
+        // The root commit is compared with the empty tree.
+        let root = test_repo
+            .repo
+            .find_commit(oid)
+            .unwrap()
+            .parent_id(0)
+            .unwrap();
+        assert_eq!(changes(root), vec![("README.md".to_string(), "added")]);
+    }
+
+    #[test]
+    fn test_git2_repository_file_patches() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Edit README",
+                &[("README.md", "# Test Repository\n\nMore text\n")],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch = BranchName::new("main-branch".to_string()).unwrap();
+        let options = FileComparisonOptions {
+            patch_context: Some(3),
+            ..FileComparisonOptions::default()
+        };
+        let changes = git_repo
+            .get_file_changes_between_branches(&branch, &options)
+            .unwrap();
+
+        let patch = changes[0].patch().unwrap();
+        assert!(!patch.is_binary());
+        let hunk = &patch.hunks()[0];
+        assert_eq!(
+            (
+                hunk.old_start,
+                hunk.old_lines,
+                hunk.new_start,
+                hunk.new_lines
+            ),
+            (1, 1, 1, 3)
+        );
+        let lines: Vec<_> = hunk
+            .lines
+            .iter()
+            .map(|line| (line.kind.as_str(), line.content.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("context", "# Test Repository"),
+                ("added", ""),
+                ("added", "More text"),
+            ]
+        );
+
+        let changes = git_repo
+            .get_file_changes_between_branches(&branch, &FileComparisonOptions::default())
+            .unwrap();
+        assert!(changes[0].patch().is_none());
+    }
+
+    #[test]
+    fn test_git2_repository_submodule_changes() {
+        if !git_available() {
+            eprintln!("git is not available; skipping");
+            return;
+        }
+        let library = TestGitRepo::new().unwrap();
+        let library_path = library.temp_dir.path().to_str().unwrap();
+        let old_commit = library.repo.head().unwrap().target().unwrap();
+
+        let test_repo = TestGitRepo::new().unwrap();
+        let root = test_repo.temp_dir.path();
+        run_git(
+            root,
+            &["submodule", "add", "-q", library_path, "vendor/library"],
+        )
+        .unwrap();
+        run_git(root, &["commit", "-q", "-m", "Add library"]).unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+
+        library
+            .create_commit_on_current_branch("Add parser", &[("parser.rs", "fn parse() {}\n")])
+            .unwrap();
+        let new_commit = library
+            .create_commit_on_current_branch("Fix parser", &[("parser.rs", "fn parse() {}\n\n")])
+            .unwrap();
+        let submodule_dir = root.join("vendor/library");
+        run_git(&submodule_dir, &["pull", "-q", "origin", "HEAD"]).unwrap();
+        run_git(root, &["commit", "-q", "-am", "Bump library"]).unwrap();
+
+        let git_repo = GitRepositoryImpl::open(root.to_str().unwrap()).unwrap();
+        let branch = BranchName::new("main-branch".to_string()).unwrap();
+        let changes = git_repo
+            .get_file_changes_between_branches(&branch, &FileComparisonOptions::default())
+            .unwrap();
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path().to_string(), "vendor/library");
+        let submodule = changes[0].submodule().unwrap();
+        assert_eq!(
+            submodule.old_commit().map(|hash| hash.to_string()),
+            Some(old_commit.to_string())
+        );
+        assert_eq!(
+            submodule.new_commit().map(|hash| hash.to_string()),
+            Some(new_commit.to_string())
+        );
+        assert!(submodule.commits().is_none());
+
+        let options = FileComparisonOptions {
+            recurse_submodules: true,
+            ..FileComparisonOptions::default()
+        };
+        let changes = git_repo
+            .get_file_changes_between_branches(&branch, &options)
+            .unwrap();
+        let messages: Vec<&str> = changes[0]
+            .submodule()
+            .and_then(|submodule| submodule.commits())
+            .unwrap()
+            .iter()
+            .map(|commit| commit.message())
+            .collect();
+        assert_eq!(messages, vec!["Fix parser", "Add parser"]);
+    }
+
+    #[test]
+    fn test_git2_repository_commits_in_topological_order() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+
+        // "Fix parser" has an older committer date than its parent, as after
+        // a rebase on a machine with a skewed clock
+        let repo = &test_repo.repo;
+        let tree = repo.head().unwrap().peel_to_tree().unwrap();
+        let base = repo.head().unwrap().peel_to_commit().unwrap();
+        let now = base.time().seconds();
+        let commit = |message: &str, offset: i64, parents: &[&git2::Commit]| {
+            let signature = Signature::new(
+                "Test User",
+                "test@example.com",
+                &git2::Time::new(now + offset, 0),
+            )
+            .unwrap();
+            let oid = repo
+                .commit(None, &signature, &signature, message, &tree, parents)
+                .unwrap();
+            repo.find_commit(oid).unwrap()
+        };
+        let add = commit("Add parser", 4000, &[&base]);
+        let fix = commit("Fix parser", 1000, &[&add]);
+        let docs = commit("Add docs", 3000, &[&base]);
+        let merge = commit("Merge docs", 5000, &[&fix, &docs]);
+        repo.reference("refs/heads/master", merge.id(), true, "merge")
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch = BranchName::new("main-branch".to_string()).unwrap();
+        let messages: Vec<String> = git_repo
+            .get_commits_not_in_branch(&branch, None)
+            .unwrap()
+            .iter()
+            .map(|commit| commit.message().to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["Merge docs", "Add docs", "Fix parser", "Add parser"]
+        );
+    }
+
+    #[test]
+    fn test_git2_repository_generated_files() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Add generated files",
+                &[
+                    (
+                        ".gitattributes",
+                        "src/schema.rs linguist-generated\n*.min.js -diff\nsrc/lib.rs linguist-generated=false\n",
+                    ),
+                    ("src/schema.rs", "pub struct Schema;\n"),
+                    ("web/app.min.js", "var a=1;\n"),
+                    ("src/lib.rs", "pub mod schema;\n"),
+                ],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch = BranchName::new("main-branch".to_string()).unwrap();
+        let generated = || -> Vec<(String, bool)> {
+            git_repo
+                .get_file_changes_between_branches(&branch, &FileComparisonOptions::default())
+                .unwrap()
+                .iter()
+                .map(|change| (change.path().to_string(), change.is_generated()))
+                .collect()
+        };
+        let expected = vec![
+            (".gitattributes".to_string(), false),
+            ("src/lib.rs".to_string(), false),
+            ("src/schema.rs".to_string(), true),
+            ("web/app.min.js".to_string(), true),
+        ];
+        assert_eq!(generated(), expected);
+
+        // Staged and uncommitted attribute edits don't change the comparison
+        fs::write(
+            test_repo.temp_dir.path().join(".gitattributes"),
+            "src/lib.rs linguist-generated\n",
+        )
+        .unwrap();
+        let mut index = test_repo.repo.index().unwrap();
+        index.add_path(Path::new(".gitattributes")).unwrap();
+        index.write().unwrap();
+        assert_eq!(generated(), expected);
+    }
+
+    #[test]
+    fn test_git2_repository_whitespace_only_changes() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Reformat",
+                &[
+                    ("README.md", "#  Test Repository\r\n"),
+                    ("src/lib.rs", "fn a() {}\n"),
+                ],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch = BranchName::new("main-branch".to_string()).unwrap();
+        let whitespace_only = |options: &FileComparisonOptions| -> Vec<(String, bool)> {
+            git_repo
+                .get_file_changes_between_branches(&branch, options)
+                .unwrap()
+                .iter()
+                .map(|change| (change.path().to_string(), change.is_whitespace_only()))
+                .collect()
+        };
+
+        let options = FileComparisonOptions {
+            line_stats: true,
+            whitespace: WhitespaceOptions {
+                ignore_space_change: true,
+                ignore_cr_at_eol: true,
+                ..WhitespaceOptions::default()
+            },
+            ..FileComparisonOptions::default()
+        };
+        assert_eq!(
+            whitespace_only(&options),
+            vec![
+                ("README.md".to_string(), true),
+                ("src/lib.rs".to_string(), false),
+            ]
+        );
+
+        let options = FileComparisonOptions {
+            whitespace: WhitespaceOptions {
+                ignore_cr_at_eol: true,
+                ..WhitespaceOptions::default()
+            },
+            ..FileComparisonOptions::default()
+        };
+        assert!(!whitespace_only(&options)[0].1);
+        assert!(!whitespace_only(&FileComparisonOptions::default())[0].1);
+    }
+
+    #[test]
+    fn test_git2_repository_ignore_cr_at_eol() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("main-branch").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Convert line endings",
+                &[
+                    ("README.md", "# Test Repository\r\n"),
+                    ("src/lib.rs", "fn a() {}\n"),
+                ],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let options = FileComparisonOptions {
+            line_stats: true,
+            whitespace: WhitespaceOptions {
+                ignore_cr_at_eol: true,
+                ..WhitespaceOptions::default()
+            },
+            ..FileComparisonOptions::default()
+        };
+        let changes = |branch: &str| -> Vec<(String, bool, LineStats)> {
+            let branch = BranchName::new(branch.to_string()).unwrap();
+            git_repo
+                .get_file_changes_between_branches(&branch, &options)
+                .unwrap()
+                .iter()
+                .map(|change| {
+                    (
+                        change.path().to_string(),
+                        change.is_whitespace_only(),
+                        change.lines().unwrap(),
+                    )
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            changes("main-branch")[0],
+            ("README.md".to_string(), true, LineStats::new(0, 0))
+        );
+
+        test_repo.create_branch("crlf").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Add trailing spaces",
+                &[
+                    ("README.md", "# Test Repository  \r\n"),
+                    ("src/lib.rs", "fn a() {}\r\n"),
+                ],
+            )
+            .unwrap();
+
+        // Trailing spaces are a real change; only the carriage return is not.
+        assert_eq!(
+            changes("crlf"),
+            vec![
+                ("README.md".to_string(), false, LineStats::new(1, 1)),
+                ("src/lib.rs".to_string(), true, LineStats::new(0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_git2_repository_file_line_stats() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("feature").unwrap();
+        test_repo.checkout_branch("feature").unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Add source",
+                &[("src/lib.rs", "fn a() {}\nfn b() {}\n")],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let branch_name = BranchName::new("master".to_string()).unwrap();
+        let options = FileComparisonOptions {
+            line_stats: true,
+            ..FileComparisonOptions::default()
+        };
+        let file_changes = git_repo
+            .get_file_changes_between_branches(&branch_name, &options)
+            .unwrap();
+
+        assert_eq!(file_changes.len(), 1);
+        assert_eq!(file_changes[0].lines(), Some(LineStats::new(2, 0)));
+    }
+
+    #[test]
+    fn test_git2_repository_file_at_head() {
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "Add code owners",
+                &[(".github/CODEOWNERS", "* @team\n")],
+            )
+            .unwrap();
+        // Uncommitted edits must not be picked up.
+        fs::write(
+            test_repo.temp_dir.path().join(".github/CODEOWNERS"),
+            "* @someone-else\n",
+        )
+        .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let contents = git_repo
+            .get_file_at_head(&FilePath::new(".github/CODEOWNERS".to_string()).unwrap())
+            .unwrap();
+        assert_eq!(contents.as_deref(), Some("* @team\n"));
+
+        let missing = git_repo
+            .get_file_at_head(&FilePath::new("CODEOWNERS".to_string()).unwrap())
+            .unwrap();
+        assert_eq!(missing, None);
+
+        // Earlier commits are read from their own tree
+        let root = test_repo
+            .repo
+            .head()
+            .unwrap()
+            .peel_to_commit()
+            .unwrap()
+            .parent_id(0)
+            .unwrap();
+        let root = CommitHash::new(root.to_string()).unwrap();
+        let readme = git_repo
+            .get_file_at_commit(&root, &FilePath::new("README.md".to_string()).unwrap())
+            .unwrap();
+        assert_eq!(readme.as_deref(), Some("# Test Repository\n"));
+        let missing = git_repo
+            .get_file_at_commit(
+                &root,
+                &FilePath::new(".github/CODEOWNERS".to_string()).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn test_git2_repository_tags_and_commit_bodies() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let head = test_repo.repo.head().unwrap().peel_to_commit().unwrap();
+        test_repo
+            .repo
+            .tag_lightweight("v1.0.0", head.as_object(), false)
+            .unwrap();
+        test_repo
+            .create_commit_on_current_branch(
+                "feat!: rename output fields\n\nBREAKING CHANGE: `path` is now `file`",
+                &[("src/lib.rs", "pub fn lib() {}\n")],
+            )
+            .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let tag = BranchName::new("v1.0.0".to_string()).unwrap();
+        let head_commits = git_repo.get_commits_not_in_branch(&tag, None).unwrap();
+        assert_eq!(head_commits.len(), 1);
+        assert_eq!(head_commits[0].message(), "feat!: rename output fields");
+        assert_eq!(
+            head_commits[0].body(),
+            "BREAKING CHANGE: `path` is now `file`"
+        );
+        let conventional = head_commits[0].conventional().unwrap();
+        assert_eq!(conventional.breaking_change(), Some("`path` is now `file`"));
+        assert_eq!(
+            head_commits[0].parents(),
+            &[CommitHash::new(head.id().to_string()).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_git2_repository_resolves_tags() {
+        let test_repo = TestGitRepo::new().unwrap();
+        let initial = test_repo.repo.head().unwrap().peel_to_commit().unwrap();
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        test_repo
+            .repo
+            .tag(
+                "v1.0.0",
+                initial.as_object(),
+                &signature,
+                "Release 1.0.0",
+                false,
+            )
+            .unwrap();
+        test_repo
+            .create_commit_on_current_branch("Add feature", &[("feature.txt", "feature\n")])
+            .unwrap();
+        let head = test_repo.repo.head().unwrap().peel_to_commit().unwrap();
+        test_repo
+            .repo
+            .tag_lightweight("release", head.as_object(), false)
+            .unwrap();
+        test_repo.repo.branch("release", &initial, false).unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+
+        // Annotated tags resolve to the commit they point at, for file
+        // comparisons as well as commit lists.
+        let tag = BranchName::new("v1.0.0".to_string()).unwrap();
+        let commits = git_repo.get_commits_not_in_branch(&tag, None).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message(), "Add feature");
+        let files = git_repo
+            .get_file_changes_between_branches(&tag, &FileComparisonOptions::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path().to_string(), "feature.txt");
+
+        // The local branch shadows the tag of the same name.
+        let release = BranchName::new("release".to_string()).unwrap();
+        let commits = git_repo.get_commits_not_in_branch(&release, None).unwrap();
+        assert_eq!(commits.len(), 1);
+    }
+
+    #[test]
+    fn test_git2_repository_ssh_signatures() {
+        let keys = tempfile::tempdir().unwrap();
+        let Some(key) = generate_ssh_key(keys.path()) else {
+            eprintln!("ssh-keygen is not available; skipping");
+            return;
+        };
+        let other_keys = keys.path().join("other");
+        fs::create_dir(&other_keys).unwrap();
+        let other_key = generate_ssh_key(&other_keys).unwrap();
+
+        let test_repo = TestGitRepo::new().unwrap();
+        test_repo.create_branch("base").unwrap();
+        test_repo
+            .create_ssh_signed_commit("Signed by a trusted key", &key)
+            .unwrap();
+        test_repo
+            .create_ssh_signed_commit("Signed by an unknown key", &other_key)
+            .unwrap();
+
+        let public_key = fs::read_to_string(key.with_extension("pub")).unwrap();
+        let allowed_signers = keys.path().join("allowed_signers");
+        fs::write(
+            &allowed_signers,
+            format!("test@example.com namespaces=\"git\" {}", public_key),
+        )
+        .unwrap();
+
+        let git_repo =
+            GitRepositoryImpl::open(test_repo.temp_dir.path().to_str().unwrap()).unwrap();
+        let base = BranchName::new("base".to_string()).unwrap();
+        let commits = git_repo.get_commits_not_in_branch(&base, None).unwrap();
+        let formats: Vec<_> = commits.iter().map(|commit| commit.signature()).collect();
+        assert_eq!(
+            formats,
+            vec![Some(SignatureFormat::Ssh), Some(SignatureFormat::Ssh)]
+        );
+
+        let initial = test_repo.repo.revparse_single("base").unwrap().id();
+        let mut hashes: Vec<CommitHash> =
+            commits.iter().map(|commit| commit.hash().clone()).collect();
+        hashes.push(CommitHash::new(initial.to_string()).unwrap());
+        let verify = |verification: &SignatureVerification| -> Vec<SignatureStatus> {
+            hashes
+                .iter()
+                .map(|hash| {
+                    git_repo
+                        .verify_commit_signature(hash, verification)
+                        .unwrap()
+                })
+                .collect()
+        };
+        assert_eq!(
+            verify(&SignatureVerification::default()),
+            vec![
+                SignatureStatus::UnknownKey,
+                SignatureStatus::UnknownKey,
+                SignatureStatus::Unsigned
+            ]
+        );
+        assert_eq!(
+            verify(&SignatureVerification {
+                gpg_keyring: None,
+                allowed_signers: Some(allowed_signers),
+            }),
+            vec![
+                SignatureStatus::UnknownKey,
+                SignatureStatus::Good,
+                SignatureStatus::Unsigned
+            ]
+        );
+    }
 }